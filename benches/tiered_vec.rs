@@ -1,5 +1,7 @@
+#![allow(dead_code)]
+
 use ::tiered_vec::{FlatTieredVec, LinkedTieredVec};
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 
@@ -67,7 +69,7 @@ mod linked_tiered_vec {
     ) {
         let mut len = tiered_vec.len();
         let mut weights = [tiered_vec.capacity() - len, len, len];
-        let mut dist = WeightedIndex::new(&weights).unwrap();
+        let mut dist = WeightedIndex::new(weights).unwrap();
 
         for _ in 0..num_operations {
             match dist.sample(&mut rng) {
@@ -99,7 +101,7 @@ mod linked_tiered_vec {
             }
 
             weights = [tiered_vec.capacity() - len, len, len];
-            dist = WeightedIndex::new(&weights).unwrap();
+            dist = WeightedIndex::new(weights).unwrap();
         }
     }
 }
@@ -161,7 +163,7 @@ mod flat_tiered_vec {
     ) {
         let mut len = tiered_vec.len();
         let mut weights = [tiered_vec.capacity() - len, len, len];
-        let mut dist = WeightedIndex::new(&weights).unwrap();
+        let mut dist = WeightedIndex::new(weights).unwrap();
 
         for _ in 0..num_operations {
             match dist.sample(&mut rng) {
@@ -193,7 +195,7 @@ mod flat_tiered_vec {
             }
 
             weights = [tiered_vec.capacity() - len, len, len];
-            dist = WeightedIndex::new(&weights).unwrap();
+            dist = WeightedIndex::new(weights).unwrap();
         }
     }
 }
@@ -221,7 +223,7 @@ mod vec {
         }
     }
 
-    pub fn update(mut rng: SmallRng, vec: &mut Vec<usize>, num_updates: usize) {
+    pub fn update(mut rng: SmallRng, vec: &mut [usize], num_updates: usize) {
         let len = vec.len();
 
         for i in 0..num_updates {
@@ -247,7 +249,7 @@ mod vec {
     pub fn random_mix(mut rng: SmallRng, vec: &mut Vec<usize>, num_operations: usize) {
         let mut len = vec.len();
         let mut weights = [vec.capacity() - len, len, len];
-        let mut dist = WeightedIndex::new(&weights).unwrap();
+        let mut dist = WeightedIndex::new(weights).unwrap();
 
         for _ in 0..num_operations {
             match dist.sample(&mut rng) {
@@ -275,7 +277,7 @@ mod vec {
             }
 
             weights = [vec.capacity() - len, len, len];
-            dist = WeightedIndex::new(&weights).unwrap();
+            dist = WeightedIndex::new(weights).unwrap();
         }
     }
 }
@@ -288,7 +290,7 @@ fn bench_insert_worst(c: &mut Criterion) {
         let mut group = c.benchmark_group(format!("Insertion Worst Case {}", vec_size));
 
         let mut tv = LinkedTieredVec::with_capacity(vec_size);
-        let mut ftv: FlatTieredVec<usize> = FlatTieredVec::new(tv.tier_capacity());
+        let mut ftv: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
         let mut v: Vec<usize> = Vec::with_capacity(tv.capacity());
 
         for i in 0..starting_size {
@@ -299,21 +301,18 @@ fn bench_insert_worst(c: &mut Criterion) {
 
         group.bench_function("Vec", |b| {
             b.iter(|| {
-                let rng = SmallRng::seed_from_u64(256);
                 vec::insert_at(0, black_box(&mut v), vec_size);
             })
         });
 
         group.bench_function("LinkedTieredVec", |b| {
             b.iter(|| {
-                let rng = SmallRng::seed_from_u64(256);
                 linked_tiered_vec::insert_at(0, black_box(&mut tv), vec_size);
             })
         });
 
         group.bench_function("FlatTieredVec", |b| {
             b.iter(|| {
-                let rng = SmallRng::seed_from_u64(256);
                 flat_tiered_vec::insert_at(0, black_box(&mut ftv), vec_size);
             })
         });
@@ -332,7 +331,7 @@ fn bench_insert_best(c: &mut Criterion) {
         let mut group = c.benchmark_group(format!("Insertion Best Case {}", vec_size));
 
         let mut tv = LinkedTieredVec::with_capacity(vec_size);
-        let mut ftv: FlatTieredVec<usize> = FlatTieredVec::new(tv.tier_capacity());
+        let mut ftv: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
         let mut v: Vec<usize> = Vec::with_capacity(tv.capacity());
 
         for i in 0..starting_size {
@@ -343,21 +342,18 @@ fn bench_insert_best(c: &mut Criterion) {
 
         group.bench_function("Vec", |b| {
             b.iter(|| {
-                let rng = SmallRng::seed_from_u64(256);
                 vec::insert_at(v.len(), black_box(&mut v), vec_size);
             })
         });
 
         group.bench_function("LinkedTieredVec", |b| {
             b.iter(|| {
-                let rng = SmallRng::seed_from_u64(256);
                 linked_tiered_vec::insert_at(tv.len(), black_box(&mut tv), vec_size);
             })
         });
 
         group.bench_function("FlatTieredVec", |b| {
             b.iter(|| {
-                let rng = SmallRng::seed_from_u64(256);
                 flat_tiered_vec::insert_at(ftv.len(), black_box(&mut ftv), vec_size);
             })
         });
@@ -376,7 +372,7 @@ fn bench_insert_random(c: &mut Criterion) {
         let mut group = c.benchmark_group(format!("Insertion Random {}", vec_size));
 
         let mut tv = LinkedTieredVec::with_capacity(vec_size);
-        let mut ftv: FlatTieredVec<usize> = FlatTieredVec::new(tv.tier_capacity());
+        let mut ftv: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
         let mut v: Vec<usize> = Vec::with_capacity(tv.capacity());
 
         for i in 0..starting_size {
@@ -417,7 +413,7 @@ fn bench_update(c: &mut Criterion) {
 
     let vec_size: usize = 1_000;
     let mut tv = LinkedTieredVec::with_capacity(vec_size);
-    let mut ftv = FlatTieredVec::new(tv.tier_capacity());
+    let mut ftv = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
     let mut v: Vec<_> = Vec::with_capacity(tv.capacity());
 
     for i in 0..vec_size {
@@ -478,7 +474,7 @@ fn bench_delete(c: &mut Criterion) {
 
     let vec_size: usize = 1_000;
     let mut tv = LinkedTieredVec::with_capacity(vec_size);
-    let mut ftv = FlatTieredVec::new(tv.tier_capacity());
+    let mut ftv = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
     let mut v: Vec<_> = Vec::with_capacity(tv.capacity());
 
     for i in 0..vec_size {
@@ -538,12 +534,73 @@ fn bench_delete(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_delete_worst(c: &mut Criterion) {
+    let vec_size: usize = 100_000;
+    let num_deletes: usize = 1_000;
+
+    let mut group = c.benchmark_group(format!("Deletion Worst Case {}", vec_size));
+
+    let mut tv = LinkedTieredVec::with_capacity(vec_size);
+    let mut ftv: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
+    let mut v: Vec<usize> = Vec::with_capacity(tv.capacity());
+
+    for i in 0..vec_size {
+        tv.push(i);
+        ftv.push(i);
+        v.push(i);
+    }
+
+    group.bench_function("Vec", |b| {
+        b.iter_batched(
+            || v.clone(),
+            |mut v| vec::delete_at(0, black_box(&mut v), num_deletes),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("LinkedTieredVec", |b| {
+        b.iter_batched(
+            || tv.clone(),
+            |mut tv| linked_tiered_vec::delete_at(0, black_box(&mut tv), num_deletes),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("FlatTieredVec", |b| {
+        b.iter_batched(
+            || ftv.clone(),
+            |mut ftv| flat_tiered_vec::delete_at(0, black_box(&mut ftv), num_deletes),
+            BatchSize::LargeInput,
+        )
+    });
+
+    // It's recommended to call group.finish() explicitly at the end, but if you don't it will
+    // be called automatically when the group is dropped.
+    group.finish();
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let vec_size: usize = 1_000_000;
+
+    let mut group = c.benchmark_group(format!("Clone {}", vec_size));
+
+    let ftv: FlatTieredVec<usize> = (0..vec_size).collect();
+
+    group.bench_function("FlatTieredVec", |b| b.iter(|| black_box(&ftv).clone()));
+
+    group.bench_function("FlatTieredVec bitwise", |b| {
+        b.iter(|| black_box(&ftv).clone_bitwise())
+    });
+
+    group.finish();
+}
+
 fn bench_random_mix(c: &mut Criterion) {
     let mut group = c.benchmark_group("Random Mix");
 
     let vec_size: usize = 1_000;
     let tv: LinkedTieredVec<usize> = LinkedTieredVec::with_capacity(vec_size);
-    let ftv: FlatTieredVec<usize> = FlatTieredVec::new(tv.tier_capacity());
+    let ftv: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(tv.tier_capacity());
     let mut v: Vec<usize> = Vec::with_capacity(tv.capacity());
 
     group.bench_function("Vec", |b| {
@@ -577,7 +634,9 @@ criterion_group!(
     bench_insert_worst,
     bench_insert_best,
     bench_insert_random,
-    // bench_delete,
+    bench_delete,
+    bench_delete_worst,
+    bench_clone,
     // bench_update,
     // bench_random_mix_half_update,
     // bench_random_mix
@@ -0,0 +1,282 @@
+use std::{ops::Index, slice};
+
+use rkyv::{
+    bytecheck::CheckBytes,
+    munge::munge,
+    rancor::{Fallible, Source},
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Archived, Deserialize, Place, Portable, Serialize,
+};
+
+use thiserror::Error;
+
+use crate::{FlatTieredVec, LinkedTieredVec};
+
+// elements are archived in rank order, which is the flat layout with its ring normalized, so
+// every tier of the archive is one contiguous run that can be read in place
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(C)]
+pub struct ArchivedTieredVec<T> {
+    tier_capacity: Archived<usize>,
+    elements: ArchivedVec<T>,
+}
+
+pub struct TieredVecResolver {
+    elements: VecResolver,
+}
+
+#[derive(Debug, Error)]
+#[error("archived vector of len {len} could not be allocated")]
+struct RestoreError {
+    len: usize,
+}
+
+impl<T> ArchivedTieredVec<T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    #[inline]
+    pub fn tier_capacity(&self) -> usize {
+        self.tier_capacity.to_native() as usize
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elements.get(index)
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.elements.as_slice()
+    }
+
+    // the populated tiers in order, each as it would sit in a normalized vector
+    pub fn tiers(&self) -> slice::Chunks<'_, T> {
+        self.elements.chunks(self.tier_capacity().max(1))
+    }
+
+    fn resolve_from(
+        tier_capacity: usize,
+        len: usize,
+        resolver: TieredVecResolver,
+        out: Place<Self>,
+    ) {
+        munge!(let ArchivedTieredVec { tier_capacity: out_tier_capacity, elements } = out);
+        tier_capacity.resolve((), out_tier_capacity);
+        ArchivedVec::<T>::resolve_from_len(len, resolver.elements, elements);
+    }
+
+    // an archive is not trusted to hold a valid geometry, so tiers that cannot hold the elements,
+    // or are far wider than they need, fall back to sizing by length
+    fn restored_tier_capacity(&self, packed_tier_capacity: usize) -> usize {
+        let tier_capacity = self.tier_capacity();
+        let fits = tier_capacity
+            .checked_pow(2)
+            .is_some_and(|capacity| capacity >= self.len());
+
+        if tier_capacity >= 2
+            && tier_capacity.is_power_of_two()
+            && fits
+            && tier_capacity <= packed_tier_capacity << 2
+        {
+            tier_capacity
+        } else {
+            packed_tier_capacity
+        }
+    }
+
+    // an unallocated vector archives a tier capacity of 0 and is restored unallocated
+    #[inline]
+    fn is_unallocated(&self) -> bool {
+        self.is_empty() && self.tier_capacity() == 0
+    }
+
+    #[inline]
+    fn restore_error<E: Source>(&self) -> E {
+        E::new(RestoreError { len: self.len() })
+    }
+}
+
+impl<T> Index<usize> for ArchivedTieredVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
+impl<T: Archive> Archive for FlatTieredVec<T> {
+    type Archived = ArchivedTieredVec<T::Archived>;
+    type Resolver = TieredVecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedTieredVec::resolve_from(self.tier_capacity(), self.len(), resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for FlatTieredVec<T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(TieredVecResolver {
+            elements: ArchivedVec::serialize_from_iter::<T, _, _>(self.iter(), serializer)?,
+        })
+    }
+}
+
+impl<T, D> Deserialize<FlatTieredVec<T>, D> for ArchivedTieredVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<FlatTieredVec<T>, D::Error> {
+        let mut tiered_vec = if self.is_unallocated() {
+            FlatTieredVec::new()
+        } else {
+            let packed_tier_capacity = FlatTieredVec::<T>::tier_capacity_for(self.len().max(4))
+                .ok_or_else(|| self.restore_error::<D::Error>())?;
+            FlatTieredVec::try_with_tier_capacity(self.restored_tier_capacity(packed_tier_capacity))
+                .map_err(|_| self.restore_error::<D::Error>())?
+        };
+
+        for elem in self.iter() {
+            tiered_vec.push(elem.deserialize(deserializer)?);
+        }
+
+        Ok(tiered_vec)
+    }
+}
+
+impl<T: Archive> Archive for LinkedTieredVec<T> {
+    type Archived = ArchivedTieredVec<T::Archived>;
+    type Resolver = TieredVecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedTieredVec::resolve_from(self.tier_capacity(), self.len(), resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for LinkedTieredVec<T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(TieredVecResolver {
+            elements: ArchivedVec::serialize_from_iter::<T, _, _>(self.iter(), serializer)?,
+        })
+    }
+}
+
+impl<T, D> Deserialize<LinkedTieredVec<T>, D> for ArchivedTieredVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<LinkedTieredVec<T>, D::Error> {
+        let mut tiered_vec = if self.is_unallocated() {
+            LinkedTieredVec::new()
+        } else {
+            let packed_tier_capacity = LinkedTieredVec::<T>::tier_capacity_for(self.len().max(4))
+                .ok_or_else(|| self.restore_error::<D::Error>())?;
+            LinkedTieredVec::try_with_tier_capacity(
+                self.restored_tier_capacity(packed_tier_capacity),
+            )
+            .map_err(|_| self.restore_error::<D::Error>())?
+        };
+
+        for elem in self.iter() {
+            tiered_vec.push(elem.deserialize(deserializer)?);
+        }
+
+        Ok(tiered_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+
+    use super::*;
+
+    #[test]
+    fn archive_flat_in_place() {
+        let mut t: FlatTieredVec<u32> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..10).rev() {
+            t.push_front(i);
+        }
+
+        let bytes = rkyv::to_bytes::<Error>(&t).unwrap();
+        let archived = rkyv::access::<ArchivedTieredVec<Archived<u32>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 10);
+        assert_eq!(archived.tier_capacity(), 4);
+        assert_eq!(archived[3], 3);
+        assert!(archived.get(10).is_none());
+        assert_eq!(
+            archived.tiers().map(<[_]>::len).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert!(archived.iter().map(|elem| elem.to_native()).eq(0..10));
+
+        let restored: FlatTieredVec<u32> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(restored.validate(), Ok(()));
+        assert_eq!(restored.tier_capacity(), 4);
+        assert_eq!(restored, t);
+    }
+
+    #[test]
+    fn archive_linked_round_trip() {
+        let t: LinkedTieredVec<String> = (0..20).map(|i| i.to_string()).collect();
+
+        let bytes = rkyv::to_bytes::<Error>(&t).unwrap();
+        let restored: LinkedTieredVec<String> =
+            rkyv::from_bytes::<LinkedTieredVec<String>, Error>(&bytes).unwrap();
+        assert_eq!(restored.validate(), Ok(()));
+        assert_eq!(restored.tier_capacity(), t.tier_capacity());
+        assert_eq!(restored, t);
+
+        let empty: LinkedTieredVec<String> = LinkedTieredVec::new();
+        let bytes = rkyv::to_bytes::<Error>(&empty).unwrap();
+        let restored = rkyv::from_bytes::<LinkedTieredVec<String>, Error>(&bytes).unwrap();
+        assert_eq!(restored.capacity(), 0);
+    }
+
+    #[test]
+    fn tampered_tier_capacity() {
+        let t: FlatTieredVec<u32> = (0..1).collect();
+        let mut bytes = rkyv::to_bytes::<Error>(&t).unwrap();
+
+        // the root sits at the end of the buffer and starts with the recorded tier capacity
+        let root = bytes.len() - size_of::<ArchivedTieredVec<Archived<u32>>>();
+        bytes[root..root + 4].copy_from_slice(&(1u32 << 20).to_le_bytes());
+        let archived = rkyv::access::<ArchivedTieredVec<Archived<u32>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.tier_capacity(), 1 << 20);
+
+        let restored = rkyv::from_bytes::<FlatTieredVec<u32>, Error>(&bytes).unwrap();
+        assert_eq!(restored.validate(), Ok(()));
+        assert_eq!(restored.capacity(), 4);
+        assert_eq!(restored, t);
+
+        let restored = rkyv::from_bytes::<LinkedTieredVec<u32>, Error>(&bytes).unwrap();
+        assert_eq!(restored.validate(), Ok(()));
+        assert_eq!(restored.capacity(), 4);
+        assert!(restored.iter().copied().eq(0..1));
+    }
+}
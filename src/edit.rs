@@ -0,0 +1,15 @@
+// a single step of an edit script, addressed by rank in the vector before any edit is applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    Insert(usize, T),
+    Remove(usize),
+    Replace(usize, T),
+}
+
+impl<T> Edit<T> {
+    pub fn rank(&self) -> usize {
+        match self {
+            Edit::Insert(rank, _) | Edit::Remove(rank) | Edit::Replace(rank, _) => *rank,
+        }
+    }
+}
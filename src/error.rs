@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+// failures of the fallible operations, handing back any element that could not be placed
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TieredVectorError<T> {
+    #[error("index {index} is past the end of a vector of len {len}")]
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+        elem: Option<T>,
+    },
+    #[error("vector is fixed at a capacity of {bound} elements")]
+    CapacityBound { bound: usize, elem: T },
+    #[error("vector is empty")]
+    Empty,
+}
+
+impl<T> TieredVectorError<T> {
+    pub fn into_elem(self) -> Option<T> {
+        match self {
+            TieredVectorError::IndexOutOfBounds { elem, .. } => elem,
+            TieredVectorError::CapacityBound { elem, .. } => Some(elem),
+            TieredVectorError::Empty => None,
+        }
+    }
+}
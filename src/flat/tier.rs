@@ -1,9 +1,12 @@
 use std::{
     alloc::{Layout, LayoutError},
     mem::{size_of, MaybeUninit},
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
+    ptr, slice,
 };
 
+use crate::SlotState;
+
 #[repr(C)]
 pub struct Tier<T> {
     head: usize,
@@ -25,6 +28,16 @@ impl<T> Tier<T> {
         Ok(layout.pad_to_align())
     }
 
+    #[inline]
+    pub fn elements_offset() -> usize {
+        let offsets = Layout::array::<usize>(2).expect("metadata layout should be valid");
+        let (_, offset) = offsets
+            .extend(Layout::new::<MaybeUninit<T>>())
+            .expect("tier layout should be valid");
+
+        offset
+    }
+
     #[inline]
     pub const fn len(&self) -> usize {
         self.tail.wrapping_sub(self.head)
@@ -62,7 +75,7 @@ impl<T> Tier<T> {
 
     #[inline]
     pub fn tail_forward_by(&mut self, extend_count: usize) {
-        self.tail += extend_count;
+        self.tail = self.tail.wrapping_add(extend_count);
     }
 
     #[inline]
@@ -81,6 +94,11 @@ impl<T> Tier<T> {
         self.tail = 0;
     }
 
+    #[inline]
+    pub(crate) fn raw_parts(&self) -> (usize, usize, &[MaybeUninit<T>]) {
+        (self.head, self.tail, &self.elements)
+    }
+
     #[inline]
     pub(crate) const fn masked_head(&self) -> usize {
         self.mask(self.head)
@@ -115,7 +133,30 @@ impl<T> Tier<T> {
         self.contains_masked_rank(self.masked_rank(rank))
     }
 
-    fn get<'a>(&'a self, index: usize) -> Option<&'a T> {
+    /// # Safety
+    ///
+    /// `tier` must point to an initialized tier, and `rank` must be occupied.
+    pub(crate) unsafe fn slot_ptr(tier: *mut Self, rank: usize) -> *mut T {
+        let capacity = ptr::addr_of!((*tier).elements).len();
+        let head = ptr::addr_of!((*tier).head).read();
+        let masked_idx = head.wrapping_add(rank) & (capacity - 1);
+
+        ptr::addr_of_mut!((*tier).elements)
+            .cast::<T>()
+            .add(masked_idx)
+    }
+
+    pub fn slot_state(&self, buffer_index: usize) -> SlotState {
+        if buffer_index >= self.capacity() {
+            SlotState::OutOfRange
+        } else if self.contains_masked_rank(buffer_index) {
+            SlotState::Occupied
+        } else {
+            SlotState::Free
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&T> {
         if !self.contains_masked_rank(index) {
             return None;
         }
@@ -124,7 +165,7 @@ impl<T> Tier<T> {
         Some(unsafe { elem.assume_init_ref() })
     }
 
-    fn get_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut T> {
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
         if !self.contains_masked_rank(index) {
             return None;
         }
@@ -133,16 +174,95 @@ impl<T> Tier<T> {
         Some(unsafe { elem.assume_init_mut() })
     }
 
-    pub fn get_by_rank<'a>(&'a self, rank: usize) -> Option<&'a T> {
+    pub fn get_by_rank(&self, rank: usize) -> Option<&T> {
         let masked_rank = self.masked_rank(rank);
         self.get(masked_rank)
     }
 
-    pub fn get_by_rank_mut<'a>(&'a mut self, rank: usize) -> Option<&'a mut T> {
+    pub fn get_by_rank_mut(&mut self, rank: usize) -> Option<&mut T> {
         let masked_rank = self.masked_rank(rank);
         self.get_mut(masked_rank)
     }
 
+    // slot ranges occupied by live elements, the second of which is only non-empty when wrapped
+    pub(crate) fn masked_ranges(&self) -> (Range<usize>, Range<usize>) {
+        let masked_head = self.masked_head();
+        let end = masked_head + self.len();
+
+        if end <= self.capacity() {
+            (masked_head..end, 0..0)
+        } else {
+            (masked_head..self.capacity(), 0..end - self.capacity())
+        }
+    }
+
+    // live elements in rank order, split in two only where the ring wraps around
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.masked_ranges();
+        let elements = self.elements.as_ptr() as *const T;
+
+        unsafe {
+            (
+                slice::from_raw_parts(elements.add(first.start), first.len()),
+                slice::from_raw_parts(elements.add(second.start), second.len()),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.masked_ranges();
+        let (wrapped, rest) = self.elements.split_at_mut(first.start);
+
+        let first = &mut rest[..first.len()] as *mut [MaybeUninit<T>] as *mut [T];
+        let second = &mut wrapped[second] as *mut [MaybeUninit<T>] as *mut [T];
+
+        unsafe { (&mut *first, &mut *second) }
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len() && b < self.len());
+
+        let (masked_a, masked_b) = (self.masked_rank(a), self.masked_rank(b));
+        self.elements.swap(masked_a, masked_b);
+    }
+
+    // copies as much of `src` as fits after the tail, returning how many elements were taken
+    pub fn extend_from_slice(&mut self, src: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        let count = src.len().min(self.capacity() - self.len());
+        let masked_tail = self.masked_tail();
+        let first_count = count.min(self.capacity() - masked_tail);
+
+        unsafe {
+            let dst = self.elements.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.add(masked_tail), first_count);
+            ptr::copy_nonoverlapping(src.as_ptr().add(first_count), dst, count - first_count);
+        }
+
+        self.tail_forward_by(count);
+        count
+    }
+
+    // moves every element onto the end of `out` with at most two bulk copies, leaving the tier empty
+    pub fn drain_into(&mut self, out: &mut Vec<T>) {
+        let (first, second) = self.masked_ranges();
+        let len = self.len();
+        out.reserve(len);
+
+        unsafe {
+            let src = self.elements.as_ptr() as *const T;
+            let dst = out.as_mut_ptr().add(out.len());
+
+            ptr::copy_nonoverlapping(src.add(first.start), dst, first.len());
+            ptr::copy_nonoverlapping(src.add(second.start), dst.add(first.len()), second.len());
+            out.set_len(out.len() + len);
+        }
+
+        self.clear_and_leak();
+    }
+
     pub fn rotate_reset(&mut self) {
         self.tail = self.len();
 
@@ -225,6 +345,21 @@ impl<T> Tier<T> {
         self.replace_element(index, elem)
     }
 
+    // a `pop_push_front` per element of `run`, from its back, with each popped element taking
+    // the place in `run` of the one pushed
+    pub fn pop_push_front_run(&mut self, run: &mut [T]) {
+        assert!(self.is_full());
+        assert!(run.len() <= self.capacity());
+
+        for elem in run.iter_mut().rev() {
+            self.head_backward();
+            self.tail_backward();
+            let index = self.masked_head();
+
+            std::mem::swap(elem, unsafe { self.elements[index].assume_init_mut() });
+        }
+    }
+
     fn shift_to_head(&mut self, from: usize) {
         let mut cursor: Option<T> = None;
         let mut i = from;
@@ -295,7 +430,7 @@ impl<T> Tier<T> {
         self.tail_backward();
         let mut i = self.masked_tail();
 
-        while i > gap_masked_idx {
+        while i != gap_masked_idx {
             if let Some(elem) = cursor {
                 cursor = Some(self.replace_element(i, elem));
             } else {
@@ -324,7 +459,7 @@ impl<T> Tier<T> {
             self.close_gap(masked_rank);
         }
 
-        return elem;
+        elem
     }
 }
 
@@ -332,12 +467,26 @@ impl<T> Index<usize> for Tier<T> {
     type Output = T;
 
     fn index(&self, rank: usize) -> &Self::Output {
+        assert!(
+            rank < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            rank
+        );
+
         unsafe { self.elements[self.masked_rank(rank)].assume_init_ref() }
     }
 }
 
 impl<T> IndexMut<usize> for Tier<T> {
     fn index_mut(&mut self, rank: usize) -> &mut Self::Output {
+        assert!(
+            rank < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            rank
+        );
+
         unsafe { self.elements[self.masked_rank(rank)].assume_init_mut() }
     }
 }
@@ -345,13 +494,14 @@ impl<T> IndexMut<usize> for Tier<T> {
 #[cfg(test)]
 mod tests {
     use crate::flat::tiered_vec::FlatTieredVec;
+    use crate::SlotState;
     use std::fmt::Debug;
 
     fn prepare_tiered_vec<T>(tier_capacity: usize) -> FlatTieredVec<T>
     where
         T: Debug,
     {
-        FlatTieredVec::new(tier_capacity)
+        FlatTieredVec::with_tier_capacity(tier_capacity)
     }
 
     #[test]
@@ -376,6 +526,21 @@ mod tests {
         assert!(!tv.tier(0).contains_rank(3));
     }
 
+    #[test]
+    fn pop_push_front_run() {
+        let mut tv: FlatTieredVec<String> = prepare_tiered_vec(4);
+        for i in 0..4 {
+            tv.tier_mut(0).push_back(i.to_string());
+        }
+
+        let mut run = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        tv.tier_mut(0).pop_push_front_run(&mut run);
+
+        assert_eq!(run, ["1", "2", "3"]);
+        let (front, back) = tv.tier(0).as_slices();
+        assert!(front.iter().chain(back).eq(["a", "b", "c", "0"].iter()));
+    }
+
     #[test]
     fn insert_at_rank_shift_tail() {
         let mut tv: FlatTieredVec<usize> = prepare_tiered_vec(4);
@@ -643,4 +808,50 @@ mod tests {
         assert!(tv.tier(0).contains_masked_rank(2));
         assert!(tv.tier(0).contains_masked_rank(3));
     }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn index_empty_tier() {
+        let tv: FlatTieredVec<usize> = prepare_tiered_vec(4);
+        let _ = tv.tier(0)[0];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn index_past_len() {
+        let mut tv: FlatTieredVec<usize> = prepare_tiered_vec(4);
+        tv.tier_mut(0).push_back(0);
+        tv.tier_mut(0).push_back(1);
+        assert_eq!(tv.tier(0)[1], 1);
+
+        tv.tier_mut(0)[2] = 2;
+    }
+
+    #[test]
+    fn slot_state() {
+        let mut tv = prepare_tiered_vec::<usize>(4);
+        let t = tv.tier_mut(0);
+        assert_eq!(t.slot_state(0), SlotState::Free);
+        assert_eq!(t.slot_state(4), SlotState::OutOfRange);
+
+        // [n, n, 0, 1]
+        t.push_back(9);
+        t.push_back(9);
+        t.pop_front();
+        t.pop_front();
+        t.push_back(0);
+        t.push_back(1);
+        assert_eq!(t.slot_state(2), SlotState::Occupied);
+        assert_eq!(t.slot_state(3), SlotState::Occupied);
+        assert_eq!(t.slot_state(0), SlotState::Free);
+
+        // [2, n, 0, 1]
+        t.push_back(2);
+        assert_eq!(t.slot_state(0), SlotState::Occupied);
+        assert_eq!(t.slot_state(1), SlotState::Free);
+
+        // [2, 3, 0, 1]
+        t.push_back(3);
+        assert_eq!(t.slot_state(1), SlotState::Occupied);
+    }
 }
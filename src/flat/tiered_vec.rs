@@ -1,55 +1,264 @@
 use std::{
-    alloc::{alloc_zeroed, dealloc, realloc, Layout, LayoutError},
+    alloc::{alloc, alloc_zeroed, dealloc, handle_alloc_error, realloc, Layout, LayoutError},
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet, VecDeque},
+    fmt::{self, Debug, Write},
+    hash::Hash,
     marker::PhantomData,
-    ops::{Index, IndexMut},
+    mem::{self, size_of, MaybeUninit},
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
     ptr,
 };
 
+#[cfg(feature = "bytemuck")]
+use std::io;
+
 use super::tier::Tier;
+use crate::{Edit, SlotState, TieredVectorError};
+
+#[cfg(feature = "bytemuck")]
+const SNAPSHOT_MAGIC: &[u8; 4] = b"TVEC";
 
 pub struct FlatTieredVec<T> {
     ptr: *mut u8,
     tier_capacity: usize,
     len: usize,
+    auto_contract: bool,
+    capacity_bound: Option<usize>,
+
+    // the tiers form a ring starting at `tier_head`, and the first of them may leave
+    // `front_gap` ranks free at its front so elements can be pushed there without cascading
+    tier_head: usize,
+    front_gap: usize,
+
     marker: PhantomData<T>,
+
+    #[cfg(test)]
+    expansions: usize,
+    // elements carried from one tier into another
+    #[cfg(test)]
+    moves: usize,
 }
 
 impl<T> FlatTieredVec<T> {
-    pub fn new(tier_capacity: usize) -> Self {
+    // allocates nothing until the first element arrives
+    pub const fn new() -> Self {
+        Self {
+            ptr: ptr::null_mut(),
+            tier_capacity: 0,
+            len: 0,
+            auto_contract: false,
+            capacity_bound: None,
+            tier_head: 0,
+            front_gap: 0,
+            marker: PhantomData,
+            #[cfg(test)]
+            expansions: 0,
+            #[cfg(test)]
+            moves: 0,
+        }
+    }
+
+    pub fn with_tier_capacity(tier_capacity: usize) -> Self {
         assert!(tier_capacity.is_power_of_two());
         assert!(tier_capacity.ge(&2));
+        assert!(
+            !Self::exceeds_allocation_limit(tier_capacity),
+            "requested capacity exceeds maximum allocation size for element type"
+        );
+
+        Self::try_with_tier_capacity(tier_capacity)
+            .unwrap_or_else(|layout| handle_alloc_error(layout))
+    }
 
+    // hands back the layout when the allocator refuses the buffer
+    pub(crate) fn try_with_tier_capacity(tier_capacity: usize) -> Result<Self, Layout> {
         let layout =
             Self::layout_for(tier_capacity).expect("memory layout for tier size should be valid");
 
         let buffer_ptr = unsafe { alloc_zeroed(layout) };
+        if buffer_ptr.is_null() {
+            return Err(layout);
+        }
 
-        Self {
+        Ok(Self {
             ptr: buffer_ptr,
             tier_capacity,
             len: 0,
+            auto_contract: false,
+            capacity_bound: None,
+            tier_head: 0,
+            front_gap: 0,
             marker: PhantomData,
+            #[cfg(test)]
+            expansions: 0,
+            #[cfg(test)]
+            moves: 0,
+        })
+    }
+
+    // the buffer comes from `alloc_zeroed`, so the elements only need to be marked as present
+    #[cfg(feature = "bytemuck")]
+    pub fn with_len_zeroed(tier_capacity: usize, len: usize) -> Self
+    where
+        T: bytemuck::Zeroable,
+    {
+        assert!(tier_capacity
+            .checked_pow(2)
+            .is_none_or(|capacity| len <= capacity));
+
+        let mut tiered_vec = Self::with_tier_capacity(tier_capacity);
+        tiered_vec.mark_zeroed_present(len);
+
+        tiered_vec
+    }
+
+    #[cfg(feature = "bytemuck")]
+    fn mark_zeroed_present(&mut self, len: usize) {
+        let tier_capacity = self.tier_capacity();
+        for (i, tier) in self.tiers_mut().enumerate() {
+            let tier_len = len.saturating_sub(i * tier_capacity).min(tier_capacity);
+            tier.tail_forward_by(tier_len);
+        }
+        self.len = len;
+    }
+
+    // a snapshot is a header of magic, element size, tier capacity and length as little-endian
+    // u64s, followed by the raw native-endian bytes of every element in rank order
+    #[cfg(feature = "bytemuck")]
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()>
+    where
+        T: bytemuck::Pod,
+    {
+        writer.write_all(SNAPSHOT_MAGIC)?;
+        for field in [size_of::<T>(), self.tier_capacity(), self.len()] {
+            writer.write_all(&(field as u64).to_le_bytes())?;
+        }
+
+        for run in self.segments() {
+            writer.write_all(bytemuck::cast_slice(run))?;
+        }
+
+        Ok(())
+    }
+
+    // reads straight into a zeroed buffer of the recorded geometry, one tier run at a time
+    #[cfg(feature = "bytemuck")]
+    pub fn read_from<R: io::Read>(reader: &mut R) -> io::Result<Self>
+    where
+        T: bytemuck::Pod,
+    {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let mut magic = [0; SNAPSHOT_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if &magic != SNAPSHOT_MAGIC {
+            return Err(invalid("not a tiered vector snapshot"));
+        }
+
+        let mut fields = [0; 3];
+        for field in fields.iter_mut() {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            *field = usize::try_from(u64::from_le_bytes(bytes))
+                .map_err(|_| invalid("snapshot header does not fit in usize"))?;
+        }
+
+        let [elem_size, tier_capacity, len] = fields;
+        if elem_size != size_of::<T>() {
+            return Err(invalid(
+                "snapshot element size does not match the element type",
+            ));
+        }
+
+        if tier_capacity == 0 && len == 0 {
+            return Ok(Self::new());
+        }
+
+        let valid_geometry = tier_capacity >= 2
+            && tier_capacity.is_power_of_two()
+            && !Self::exceeds_allocation_limit(tier_capacity)
+            && tier_capacity
+                .checked_pow(2)
+                .is_some_and(|capacity| len <= capacity);
+        if !valid_geometry {
+            return Err(invalid("snapshot has an invalid tier geometry"));
+        }
+
+        // the header is not trusted to size the buffer, so tiers far wider than the length
+        // needs fall back to sizing by length
+        let packed_tier_capacity = Self::tier_capacity_for(len.max(4))
+            .ok_or_else(|| invalid("snapshot has an invalid tier geometry"))?;
+        let tier_capacity = if tier_capacity > packed_tier_capacity << 2 {
+            packed_tier_capacity
+        } else {
+            tier_capacity
+        };
+
+        let mut tiered_vec = Self::try_with_tier_capacity(tier_capacity).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::OutOfMemory,
+                "snapshot buffer could not be allocated",
+            )
+        })?;
+        tiered_vec.mark_zeroed_present(len);
+        for run in tiered_vec.segments_mut() {
+            reader.read_exact(bytemuck::cast_slice_mut(run))?;
         }
+
+        Ok(tiered_vec)
     }
 
     pub fn with_capacity(minimum_capacity: usize) -> Self {
         assert!(minimum_capacity.ge(&4));
 
-        let mut capacity = minimum_capacity;
-        if !capacity.is_power_of_two() {
-            capacity = capacity.next_power_of_two();
-        }
+        let tier_capacity = Self::tier_capacity_for(minimum_capacity).expect("capacity overflow");
+        Self::with_tier_capacity(tier_capacity)
+    }
+
+    pub fn from_slice(elements: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut tiered_vec = Self::with_capacity(elements.len().max(4));
+        tiered_vec.extend_exact(elements.iter().cloned());
+
+        tiered_vec
+    }
+
+    // never grows past `total_capacity`, so pushes and inserts at the bound are rejected
+    pub fn fixed_capacity(total_capacity: usize) -> Self {
+        let mut tiered_vec = Self::with_capacity(total_capacity.max(4));
+        tiered_vec.capacity_bound = Some(total_capacity);
+
+        tiered_vec
+    }
+
+    #[inline]
+    fn at_capacity_bound(&self) -> bool {
+        self.capacity_bound.is_some_and(|bound| self.len() >= bound)
+    }
+
+    pub(crate) fn tier_capacity_for(minimum_capacity: usize) -> Option<usize> {
+        let mut capacity = minimum_capacity.checked_next_power_of_two()?;
 
         let trailing = capacity.trailing_zeros();
         let shift_count = if trailing & 1 == 0 {
             trailing / 2
         } else {
-            capacity = capacity << 1;
-            (trailing + 1) / 2
+            capacity = capacity.checked_mul(2)?;
+            trailing.div_ceil(2)
         };
 
-        let tier_capacity = capacity >> shift_count;
-        Self::new(tier_capacity)
+        Some(capacity >> shift_count)
+    }
+
+    fn exceeds_allocation_limit(tier_capacity: usize) -> bool {
+        size_of::<T>()
+            .checked_mul(tier_capacity)
+            .and_then(|tier_size| tier_size.checked_add(Tier::<T>::size_of_metadata()))
+            .and_then(|tier_size| tier_size.checked_mul(tier_capacity))
+            .is_none_or(|total_size| total_size > isize::MAX as usize)
     }
 
     fn layout_from(tier_layout: Layout, tier_capacity: usize) -> Result<Layout, LayoutError> {
@@ -62,17 +271,32 @@ impl<T> FlatTieredVec<T> {
     }
 
     #[inline]
-    fn size_of_tier(capacity: usize) -> usize {
-        Tier::<T>::layout_for(capacity)
+    pub fn tier_size_bytes(tier_capacity: usize) -> usize {
+        Tier::<T>::layout_for(tier_capacity)
             .expect("memory layout for tier size should be valid")
             .size()
     }
 
+    #[inline]
+    pub fn metadata_bytes() -> usize {
+        Tier::<T>::size_of_metadata()
+    }
+
     #[inline]
     pub const fn capacity(&self) -> usize {
         self.tier_capacity.pow(2)
     }
 
+    #[inline]
+    pub const fn capacity_after_expand(&self) -> usize {
+        // the first growth of a lazily created vector allocates two tiers of two
+        if self.tier_capacity == 0 {
+            return 4;
+        }
+
+        (self.tier_capacity << 1).pow(2)
+    }
+
     #[inline]
     pub const fn tier_capacity(&self) -> usize {
         self.tier_capacity
@@ -88,9 +312,121 @@ impl<T> FlatTieredVec<T> {
         self.len
     }
 
+    #[inline]
+    const fn mask(&self, val: usize) -> usize {
+        val & (self.tier_capacity() - 1)
+    }
+
+    // logical index of the tier holding `rank`, counted from `tier_head`
     #[inline]
     const fn tier_index(&self, rank: usize) -> usize {
-        rank >> self.num_tiers().ilog2()
+        (rank + self.front_gap) >> self.num_tiers().ilog2()
+    }
+
+    #[inline]
+    const fn tier_rank(&self, rank: usize) -> usize {
+        if rank + self.front_gap < self.tier_capacity() {
+            rank
+        } else {
+            self.mask(rank + self.front_gap)
+        }
+    }
+
+    // first rank held by the tier at logical index `tier_index`
+    #[inline]
+    const fn tier_start(&self, tier_index: usize) -> usize {
+        (tier_index * self.tier_capacity()).saturating_sub(self.front_gap)
+    }
+
+    #[inline]
+    const fn physical_tier(&self, tier_index: usize) -> usize {
+        (self.tier_head + tier_index) & (self.num_tiers() - 1)
+    }
+
+    #[inline]
+    fn tier_at(&self, tier_index: usize) -> &Tier<T> {
+        self.tier(self.physical_tier(tier_index))
+    }
+
+    #[inline]
+    fn tier_at_mut(&mut self, tier_index: usize) -> &mut Tier<T> {
+        self.tier_mut(self.physical_tier(tier_index))
+    }
+
+    #[inline]
+    fn has_room_at_back(&self) -> bool {
+        self.len() + self.front_gap < self.capacity()
+    }
+
+    fn reset_offsets(&mut self) {
+        debug_assert!(self.is_empty());
+
+        self.tier_head = 0;
+        self.front_gap = 0;
+    }
+
+    // pulls every tier forward into the front gap, leaving the ring where it is
+    fn close_front_gap(&mut self) {
+        if self.front_gap == 0 {
+            return;
+        }
+
+        let populated_tiers = self.populated_tiers();
+
+        for i in 0..populated_tiers.saturating_sub(1) {
+            let moved = self.front_gap.min(self.tier_at(i + 1).len());
+
+            for _ in 0..moved {
+                let elem = self.tier_at_mut(i + 1).pop_front();
+                self.tier_at_mut(i).push_back(elem);
+            }
+
+            #[cfg(test)]
+            {
+                self.moves += moved;
+            }
+        }
+
+        self.front_gap = 0;
+    }
+
+    // closes the front gap and rotates the ring back to the first tier, which is the layout
+    // growing, contracting and the bulk operations work on
+    fn normalize(&mut self) {
+        self.close_front_gap();
+
+        if self.tier_head > 0 {
+            // tiers are plain bytes in the buffer, so rotating the ring is a rotation of bytes
+            let tier_size = Self::tier_size_bytes(self.tier_capacity());
+            let wrapped = self.tier_head * tier_size;
+            let rest = (self.num_tiers() - self.tier_head) * tier_size;
+
+            let mut scratch: Vec<MaybeUninit<u8>> = Vec::with_capacity(wrapped);
+            unsafe {
+                let scratch_ptr = scratch.as_mut_ptr() as *mut u8;
+
+                ptr::copy_nonoverlapping(self.ptr, scratch_ptr, wrapped);
+                ptr::copy(self.ptr.add(wrapped), self.ptr, rest);
+                ptr::copy_nonoverlapping(scratch_ptr, self.ptr.add(rest), wrapped);
+            }
+
+            self.tier_head = 0;
+        }
+    }
+
+    // a front gap with no room left behind it only happens within a tier of full, so growing
+    // is cheaper than reclaiming the gap on every push; a bounded vector cannot grow, so it
+    // closes the gap in place at the cost of one move per tier
+    fn make_room_at_back(&mut self) {
+        if self.has_room_at_back() {
+            return;
+        }
+
+        if self.capacity_bound.is_none() {
+            self.expand();
+        } else {
+            self.close_front_gap();
+        }
     }
 
     fn raw_tier_ptr_from_capacity(&self, index: usize, tier_capacity: usize) -> *mut Tier<T> {
@@ -98,7 +434,7 @@ impl<T> FlatTieredVec<T> {
 
         unsafe {
             ptr::slice_from_raw_parts_mut(
-                self.ptr.add(index * Self::size_of_tier(tier_capacity)),
+                self.ptr.add(index * Self::tier_size_bytes(tier_capacity)),
                 tier_capacity,
             ) as _
         }
@@ -112,14 +448,46 @@ impl<T> FlatTieredVec<T> {
         let tier = unsafe { &*self.raw_tier_ptr(index) };
         assert_eq!(self.tier_capacity(), tier.elements.len());
 
-        return tier;
+        tier
     }
 
     pub(crate) fn tier_mut(&mut self, index: usize) -> &mut Tier<T> {
         let tier = unsafe { &mut *self.raw_tier_ptr(index) };
         assert_eq!(self.tier_capacity(), tier.elements.len());
 
-        return tier;
+        tier
+    }
+
+    // the ring from `tier_head` onwards, followed by the tiers it wrapped around to
+    fn tiers_in_order_mut(&mut self) -> impl Iterator<Item = &mut Tier<T>> {
+        let tier_size = Self::tier_size_bytes(self.tier_capacity());
+
+        let rest = TiersMut {
+            ptr: self.ptr.wrapping_add(self.tier_head * tier_size),
+            tier_capacity: self.tier_capacity(),
+            tier_size,
+            remaining: self.num_tiers() - self.tier_head,
+            marker: PhantomData,
+        };
+        let wrapped = TiersMut {
+            ptr: self.ptr,
+            tier_capacity: self.tier_capacity(),
+            tier_size,
+            remaining: self.tier_head,
+            marker: PhantomData,
+        };
+
+        rest.chain(wrapped)
+    }
+
+    pub(crate) fn tiers_mut(&mut self) -> TiersMut<'_, T> {
+        TiersMut {
+            ptr: self.ptr,
+            tier_capacity: self.tier_capacity(),
+            tier_size: Self::tier_size_bytes(self.tier_capacity()),
+            remaining: self.num_tiers(),
+            marker: PhantomData,
+        }
     }
 
     #[inline]
@@ -132,18 +500,83 @@ impl<T> FlatTieredVec<T> {
         self.len() == self.capacity()
     }
 
+    // a returned `&T` stays valid until the next `&mut self` call, since only those can move
+    // elements between tiers
     pub fn get(&self, index: usize) -> Option<&T> {
-        self.tier(self.tier_index(index)).get_by_rank(index)
+        if index >= self.len() {
+            return None;
+        }
+
+        self.tier_at(self.tier_index(index))
+            .get_by_rank(self.tier_rank(index))
     }
 
+    // the vector stays mutably borrowed for as long as the returned `&mut T` lives
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.tier_mut(self.tier_index(index)).get_by_rank_mut(index)
+        if index >= self.len() {
+            return None;
+        }
+
+        let tier_rank = self.tier_rank(index);
+        self.tier_at_mut(self.tier_index(index))
+            .get_by_rank_mut(tier_rank)
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
+
+    // deque-style names for the same ends
+    pub fn front(&self) -> Option<&T> {
+        self.first()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.first_mut()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.last()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
     }
 
     // todo: currently copying second tier's data twice
     //          - copy first tier's data to new location
     //          - copy second tier's data directly to final expected location
     fn expand(&mut self) {
+        assert!(
+            self.capacity_bound.is_none(),
+            "cannot grow a fixed-capacity vector"
+        );
+
+        // a lazily created vector gets its smallest layout on first growth
+        if self.tier_capacity() == 0 {
+            self.replace_tier_capacity(2);
+            return;
+        }
+
+        self.normalize();
+
+        #[cfg(test)]
+        {
+            self.expansions += 1;
+        }
+
         let curr_tier_capacity = self.tier_capacity();
         let new_tier_capacity = self.tier_capacity() << 1;
 
@@ -206,297 +639,5097 @@ impl<T> FlatTieredVec<T> {
     }
 
     pub fn insert(&mut self, index: usize, elem: T) {
-        assert!(index <= self.len());
+        if let Err(err) = self.try_insert(index, elem) {
+            panic!("{}", err);
+        }
+    }
 
-        if self.is_full() {
-            self.expand();
+    pub fn try_insert(&mut self, index: usize, elem: T) -> Result<(), TieredVectorError<T>> {
+        if index > self.len() {
+            return Err(TieredVectorError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+                elem: Some(elem),
+            });
         }
+        if self.at_capacity_bound() {
+            return Err(TieredVectorError::CapacityBound {
+                bound: self.len(),
+                elem,
+            });
+        }
+
+        self.make_room_at_back();
 
         let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
 
-        if !self.tier_mut(tier_index).is_full() {
-            self.tier_mut(tier_index).insert(index, elem);
+        // the first tier grows into its front gap rather than displacing into the next tier
+        if tier_index == 0 && self.front_gap > 0 {
+            let tier = self.tier_at_mut(0);
+            tier.insert(tier_rank, elem);
+
+            let tier_len = tier.len();
+            self.front_gap = self.front_gap.min(self.tier_capacity() - tier_len);
             self.len += 1;
 
-            return;
+            return Ok(());
+        }
+
+        if !self.tier_at(tier_index).is_full() {
+            self.tier_at_mut(tier_index).insert(tier_rank, elem);
+            self.len += 1;
+
+            return Ok(());
         }
 
         let last_tier_index = self.tier_index(self.len() - 1);
 
-        let mut tier = self.tier_mut(tier_index);
-        let mut prev_popped = Some(tier.pop_back());
-        tier.insert(index, elem);
+        let tier = self.tier_at_mut(tier_index);
+        let mut prev_popped = tier.pop_back();
+        tier.insert(tier_rank, elem);
 
-        for i in tier_index + 1..last_tier_index {
-            tier = self.tier_mut(i);
+        // carry the overflow through every full tier until one has room for it
+        for i in tier_index + 1..=last_tier_index {
+            #[cfg(test)]
+            {
+                self.moves += 1;
+            }
 
-            let prev_elem = prev_popped.take().expect("loop should always pop a value");
-            prev_popped = Some(tier.pop_push_front(prev_elem));
-        }
+            let tier = self.tier_at_mut(i);
 
-        tier = self.tier_mut(last_tier_index);
+            if !tier.is_full() {
+                tier.push_front(prev_popped);
+                self.len += 1;
+
+                return Ok(());
+            }
 
-        if tier.is_full() {
-            let prev_elem = prev_popped.take().expect("loop should always pop a value");
-            prev_popped = Some(tier.pop_push_front(prev_elem));
+            prev_popped = tier.pop_push_front(prev_popped);
+        }
 
-            tier = self.tier_mut(last_tier_index + 1);
+        #[cfg(test)]
+        {
+            self.moves += 1;
         }
 
-        tier.push_front(prev_popped.take().expect("loop should always pop a value"));
+        self.tier_at_mut(last_tier_index + 1)
+            .push_front(prev_popped);
         self.len += 1;
+
+        Ok(())
     }
 
-    pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.len());
+    // the batch takes whole tiers, opened up by moving the tiers on the shorter side of the
+    // insertion point over by a tier each, so only the remainder short of a tier cascades
+    // element by element
+    pub fn insert_all_at<I: IntoIterator<Item = T>>(&mut self, index: usize, items: I) {
+        assert!(index <= self.len());
+
+        let items: Vec<T> = items.into_iter().collect();
+        if items.is_empty() {
+            return;
+        }
 
+        let num_items = items.len();
+        assert!(
+            self.capacity_bound
+                .is_none_or(|bound| self.len() + num_items <= bound),
+            "vector is fixed at a capacity of {} elements",
+            self.capacity_bound.unwrap_or_default()
+        );
+        self.grow_once_for(num_items);
+
+        let tier_capacity = self.tier_capacity();
+        let num_tiers = self.num_tiers();
+        let populated_tiers = self.populated_tiers();
         let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+
+        let room = if tier_index == 0 {
+            tier_capacity - self.front_gap
+        } else {
+            tier_capacity
+        };
+        let num_displaced = self.tier_at(tier_index).len() - tier_rank;
+        let fits = (room - tier_rank).min(num_items + num_displaced);
+        let num_fresh_tiers = (num_items + num_displaced - fits) / tier_capacity;
 
-        if !self.tier_mut(tier_index).is_full() {
-            self.len -= 1;
-            return self.tier_mut(tier_index).remove(index);
+        let tier = self.tier_at_mut(tier_index);
+        let mut displaced = Vec::with_capacity(num_displaced);
+        while tier.len() > tier_rank {
+            displaced.push(tier.pop_back());
         }
 
-        let last_tier_index = self.tier_index(self.len() - 1);
-        let mut prev_popped = Some(self.tier_mut(last_tier_index).pop_front());
+        #[cfg(test)]
+        {
+            self.moves += num_displaced;
+        }
 
-        for i in (tier_index + 1..last_tier_index).rev() {
-            let tier = self.tier_mut(i);
+        // the empty tiers past the back trade places with whichever side is shorter
+        let later_tiers = populated_tiers.saturating_sub(tier_index + 1);
+        if num_fresh_tiers > 0 && tier_index < later_tiers {
+            for i in 0..=tier_index {
+                self.swap_physical_tiers(
+                    self.physical_tier(i),
+                    self.physical_tier(i + num_tiers - num_fresh_tiers),
+                );
+            }
 
-            let prev_elem = prev_popped.take().expect("loop should always pop a value");
-            prev_popped = Some(tier.pop_push_back(prev_elem));
+            self.tier_head = self.physical_tier(num_tiers - num_fresh_tiers);
+        } else if num_fresh_tiers > 0 {
+            for i in (tier_index + 1..populated_tiers).rev() {
+                self.swap_physical_tiers(
+                    self.physical_tier(i),
+                    self.physical_tier(i + num_fresh_tiers),
+                );
+            }
         }
 
-        let tier = self.tier_mut(tier_index);
-        let elem = tier.remove(index);
-        tier.push_back(prev_popped.take().expect("loop should always pop a value"));
+        let mut batch = items.into_iter().chain(displaced.into_iter().rev());
+        for i in 0..=num_fresh_tiers {
+            let take = if i == 0 { fits } else { tier_capacity };
+            let tier = self.tier_at_mut(tier_index + i);
 
-        self.len -= 1;
-        return elem;
-    }
+            for elem in batch.by_ref().take(take) {
+                tier.push_back(elem);
+            }
+        }
+        let mut carry: Vec<T> = batch.collect();
 
-    pub fn push(&mut self, elem: T) {
-        if self.is_full() {
-            self.expand();
+        // every later tier takes the remainder on its front and hands as many on from its back
+        let mut overflow = Vec::with_capacity(carry.len());
+        for i in tier_index + num_fresh_tiers + 1..num_tiers {
+            if carry.is_empty() {
+                break;
+            }
+
+            #[cfg(test)]
+            {
+                self.moves += carry.len();
+            }
+
+            let tier = self.tier_at_mut(i);
+            if tier.is_full() {
+                tier.pop_push_front_run(&mut carry);
+                continue;
+            }
+
+            for elem in carry.drain(..).rev() {
+                if tier.is_full() {
+                    overflow.push(tier.pop_push_front(elem));
+                } else {
+                    tier.push_front(elem);
+                }
+            }
+
+            overflow.reverse();
+            mem::swap(&mut carry, &mut overflow);
         }
 
-        let tier = self.tier_mut(self.tier_index(self.len()));
-        assert!(!tier.is_full());
+        debug_assert!(carry.is_empty());
+        self.len += num_items;
+    }
 
-        tier.push_back(elem);
-        self.len += 1;
+    pub fn insert_iter<I: IntoIterator<Item = T>>(&mut self, index: usize, items: I) {
+        self.insert_all_at(index, items);
     }
 
-    pub fn pop(&mut self) -> T {
-        assert!(!self.is_empty());
+    pub fn insert_slice(&mut self, index: usize, items: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_all_at(index, items.iter().cloned());
+    }
 
-        let tier = self.tier_mut(self.tier_index(self.len() - 1));
-        assert!(!tier.is_empty());
+    pub fn remove(&mut self, index: usize) -> T {
+        self.try_remove(index)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        let elem = tier.pop_back();
+    pub fn try_remove(&mut self, index: usize) -> Result<T, TieredVectorError<T>> {
+        if index >= self.len() {
+            return Err(TieredVectorError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+                elem: None,
+            });
+        }
 
-        self.len -= 1;
-        return elem;
-    }
+        let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+        let last_tier_index = self.populated_tiers() - 1;
 
-    // fn try_contract(&mut self, num_entries: usize) {
-    //     // only contract well below capacity to cull repeated alloc/free of memory upon reinsertion/redeletion
-    //     if num_entries < self.capacity() / 8 {
-    //         let curr_tier_capacity = self.tier_capacity();
-    //         let new_tier_capacity = curr_tier_capacity >> 1;
+        let elem = if tier_index == last_tier_index {
+            self.tier_at_mut(tier_index).remove(tier_rank)
+        } else {
+            let mut prev_popped = Some(self.tier_at_mut(last_tier_index).pop_front());
 
-    //         let split_index = new_tier_capacity >> 1;
-    //         let _ = self.offsets.split_off(split_index);
+            for i in (tier_index + 1..last_tier_index).rev() {
+                let tier = self.tier_at_mut(i);
 
-    //         let end_index = new_tier_capacity;
-    //         for i in (0..end_index).step_by(2) {
-    //             let old_start_index = i * curr_tier_capacity;
-    //             let old_end_index = old_start_index + curr_tier_capacity;
-    //             let old_tier = &mut self.buffer[old_start_index..old_end_index];
-    //             let old_ring_offsets = &mut self.offsets[i];
+                let prev_elem = prev_popped.take().expect("loop should always pop a value");
+                prev_popped = Some(tier.pop_push_back(prev_elem));
+            }
 
-    //             let new_ring_offsets = ImplicitTier::split_half(old_tier, old_ring_offsets);
-    //             self.offsets.insert(i + 1, new_ring_offsets);
-    //         }
+            let tier = self.tier_at_mut(tier_index);
+            let elem = tier.remove(tier_rank);
+            tier.push_back(prev_popped.take().expect("loop should always pop a value"));
 
-    //         let _ = self.buffer.split_off(split_index * curr_tier_capacity);
+            elem
+        };
 
-    //         assert_eq!(self.offsets.len(), new_tier_capacity);
-    //     }
-    // }
-}
+        self.len -= 1;
+        if self.is_empty() {
+            self.reset_offsets();
+        }
 
-impl<T> Index<usize> for FlatTieredVec<T> {
-    type Output = T;
+        if self.auto_contract {
+            self.try_contract(self.len());
+        }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        assert!(index < self.len());
-        &self.tier(self.tier_index(index))[index]
+        Ok(elem)
     }
-}
 
-impl<T> IndexMut<usize> for FlatTieredVec<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        assert!(index < self.len());
-        &mut self.tier_mut(self.tier_index(index))[index]
-    }
-}
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.len() && b < self.len(),
+            "swap indices {} and {} out of range for length {}",
+            a,
+            b,
+            self.len()
+        );
 
-impl<T> Drop for FlatTieredVec<T> {
-    fn drop(&mut self) {
-        for _ in 0..self.len() {
-            self.pop();
+        let (tier_a, tier_b) = (self.tier_index(a), self.tier_index(b));
+        if tier_a == tier_b {
+            let (rank_a, rank_b) = (self.tier_rank(a), self.tier_rank(b));
+            self.tier_at_mut(tier_a).swap(rank_a, rank_b);
+            return;
         }
 
-        unsafe {
-            dealloc(
-                self.ptr,
-                Self::layout_for(self.tier_capacity).expect("current layout should be valid"),
-            );
-        }
+        // distinct tiers never share a slot
+        unsafe { ptr::swap_nonoverlapping(self.slot_ptr(a), self.slot_ptr(b), 1) };
     }
-}
 
-impl<T> Clone for FlatTieredVec<T>
-where
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize)
+    where
+        T: Copy,
+    {
+        let Range { start, end } = resolve_range(src, self.len());
+        let count = end - start;
+        assert!(
+            dest <= self.len() - count,
+            "destination {} out of range for {} ranks in a vector of len {}",
+            dest,
+            count,
+            self.len()
+        );
+
+        unsafe { self.move_ranks(start, dest, count) };
+    }
+
+    // relocates the block so its first element ends up at `dest`, shifting the ranks in between
+    pub fn move_range<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        let Range { start, end } = resolve_range(src, self.len());
+        let count = end - start;
+        assert!(
+            dest <= self.len() - count,
+            "destination {} out of range for {} ranks in a vector of len {}",
+            dest,
+            count,
+            self.len()
+        );
+
+        if count == 0 || dest == start {
+            return;
+        }
+
+        // the block is parked in scratch memory while the ranks in between shift over; the
+        // scratch never takes ownership, so no element is dropped twice
+        let mut scratch: Vec<T> = Vec::with_capacity(count);
+        unsafe {
+            self.copy_out(start, scratch.as_mut_ptr(), count);
+
+            if dest < start {
+                self.move_ranks(dest, dest + count, start - dest);
+            } else {
+                self.move_ranks(end, start, dest - start);
+            }
+
+            self.copy_in(scratch.as_ptr(), dest, count);
+        }
+    }
+
+    pub fn swap_ranges<A, B>(&mut self, a: A, b: B)
+    where
+        A: RangeBounds<usize>,
+        B: RangeBounds<usize>,
+    {
+        let a = resolve_range(a, self.len());
+        let b = resolve_range(b, self.len());
+        assert_eq!(a.len(), b.len(), "swapped ranges must be the same length");
+        assert!(
+            a.end <= b.start || b.end <= a.start,
+            "swapped ranges must not overlap"
+        );
+
+        // the ranges are disjoint, so any pair of runs taken from them never overlaps either
+        let mut done = 0;
+        while done < a.len() {
+            let chunk = (a.len() - done)
+                .min(self.run_after(a.start + done))
+                .min(self.run_after(b.start + done));
+
+            unsafe {
+                ptr::swap_nonoverlapping(
+                    self.slot_ptr(a.start + done),
+                    self.slot_ptr(b.start + done),
+                    chunk,
+                );
+            }
+            done += chunk;
+        }
+    }
+
+    // how many ranks from `rank` onwards sit next to each other in memory
+    fn run_after(&self, rank: usize) -> usize {
+        let tier_index = self.tier_index(rank);
+        let slot = self.tier_at(tier_index).masked_rank(self.tier_rank(rank));
+        let tier_end = self.tier_start(tier_index + 1).min(self.len());
+
+        (tier_end - rank).min(self.tier_capacity() - slot)
+    }
+
+    // how many ranks ending just before `end` sit next to each other in memory
+    fn run_before(&self, end: usize) -> usize {
+        let last = end - 1;
+        let tier_index = self.tier_index(last);
+        let slot = self.tier_at(tier_index).masked_rank(self.tier_rank(last));
+
+        (end - self.tier_start(tier_index)).min(slot + 1)
+    }
+
+    // bitwise copies `count` ranks one contiguous run at a time, walking in whichever direction
+    // reads every source rank before it can be overwritten
+    unsafe fn move_ranks(&mut self, src: usize, dest: usize, count: usize) {
+        if dest <= src {
+            let mut done = 0;
+            while done < count {
+                let chunk = (count - done)
+                    .min(self.run_after(src + done))
+                    .min(self.run_after(dest + done));
+
+                ptr::copy(self.slot_ptr(src + done), self.slot_ptr(dest + done), chunk);
+                done += chunk;
+            }
+        } else {
+            let mut left = count;
+            while left > 0 {
+                let chunk = left
+                    .min(self.run_before(src + left))
+                    .min(self.run_before(dest + left));
+
+                left -= chunk;
+                ptr::copy(self.slot_ptr(src + left), self.slot_ptr(dest + left), chunk);
+            }
+        }
+    }
+
+    unsafe fn copy_out(&mut self, src: usize, dst: *mut T, count: usize) {
+        let mut done = 0;
+        while done < count {
+            let chunk = (count - done).min(self.run_after(src + done));
+
+            ptr::copy_nonoverlapping(self.slot_ptr(src + done), dst.add(done), chunk);
+            done += chunk;
+        }
+    }
+
+    unsafe fn copy_in(&mut self, src: *const T, dest: usize, count: usize) {
+        let mut done = 0;
+        while done < count {
+            let chunk = (count - done).min(self.run_after(dest + done));
+
+            ptr::copy_nonoverlapping(src.add(done), self.slot_ptr(dest + done), chunk);
+            done += chunk;
+        }
+    }
+
+    // backfills the hole with the last element, so nothing cascades
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len(),
+            "swap_remove index {} out of range for length {}",
+            index,
+            self.len()
+        );
+
+        let last = self.pop();
+        if index == self.len() {
+            return last;
+        }
+
+        std::mem::replace(&mut self[index], last)
+    }
+
+    pub fn push(&mut self, elem: T) {
+        if let Err(err) = self.try_push(elem) {
+            panic!("{}", err);
+        }
+    }
+
+    pub fn try_push(&mut self, elem: T) -> Result<(), TieredVectorError<T>> {
+        if self.at_capacity_bound() {
+            return Err(TieredVectorError::CapacityBound {
+                bound: self.len(),
+                elem,
+            });
+        }
+
+        self.make_room_at_back();
+
+        let tier = self.tier_at_mut(self.tier_index(self.len()));
+        assert!(!tier.is_full());
+
+        tier.push_back(elem);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    // drops from the back so a panicking destructor never leaves a dropped element counted
+    pub fn truncate(&mut self, len: usize) {
+        while self.len() > len {
+            let tier_index = self.tier_index(self.len() - 1);
+            let keep = len.saturating_sub(self.tier_start(tier_index));
+
+            while self.tier_at(tier_index).len() > keep {
+                let elem = self.tier_at_mut(tier_index).pop_back();
+                self.len -= 1;
+                drop(elem);
+            }
+
+            if keep == 0 {
+                self.tier_at_mut(tier_index).clear_and_leak();
+            }
+        }
+
+        if self.is_empty() {
+            self.reset_offsets();
+        }
+
+        if self.auto_contract {
+            self.try_contract(self.len());
+        }
+    }
+
+    // whole tiers are handed over when `at` falls on a tier boundary
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.len(),
+            "split index {} out of range for length {}",
+            at,
+            self.len()
+        );
+
+        // nothing is handed over, so the split-off vector need not allocate
+        if at == self.len() {
+            return Self::new();
+        }
+
+        let mut other = Self::with_tier_capacity(self.tier_capacity());
+
+        // only tiers after the first start at their own rank 0, so those are the ones handed over
+        if at > 0 && self.tier_rank(at) == 0 {
+            let first_tier = self.tier_index(at);
+            for i in first_tier..self.populated_tiers() {
+                let physical = self.physical_tier(i);
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        self.raw_tier_ptr(physical) as *const u8,
+                        other.raw_tier_ptr(i - first_tier) as *mut u8,
+                        Self::tier_size_bytes(self.tier_capacity()),
+                    );
+                }
+                self.tier_mut(physical).clear_and_leak();
+            }
+
+            other.len = self.len() - at;
+            self.len = at;
+        } else {
+            let mut moved = Vec::with_capacity(self.len() - at);
+            while self.len() > at {
+                moved.push(self.pop());
+            }
+
+            other.extend(moved.into_iter().rev());
+        }
+
+        if self.auto_contract {
+            self.try_contract(self.len());
+        }
+
+        other
+    }
+
+    pub fn pop(&mut self) -> T {
+        self.try_pop().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_pop(&mut self) -> Result<T, TieredVectorError<T>> {
+        if self.is_empty() {
+            return Err(TieredVectorError::Empty);
+        }
+
+        let tier = self.tier_at_mut(self.tier_index(self.len() - 1));
+        assert!(!tier.is_empty());
+
+        let elem = tier.pop_back();
+
+        self.len -= 1;
+        if self.is_empty() {
+            self.reset_offsets();
+        }
+
+        Ok(elem)
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        assert!(
+            !self.at_capacity_bound(),
+            "vector is fixed at a capacity of {} elements",
+            self.len()
+        );
+
+        if self.is_empty() {
+            self.push(elem);
+            return;
+        }
+
+        if self.front_gap > 0 {
+            self.tier_at_mut(0).push_front(elem);
+            self.front_gap -= 1;
+            self.len += 1;
+
+            return;
+        }
+
+        // with every tier in use there is no free tier to claim, so fall back to a cascade
+        if self.populated_tiers() == self.num_tiers() {
+            self.insert(0, elem);
+            return;
+        }
+
+        self.tier_head = self.physical_tier(self.num_tiers() - 1);
+        self.front_gap = self.tier_capacity() - 1;
+
+        self.tier_at_mut(0).push_front(elem);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> T {
+        assert!(!self.is_empty());
+
+        let spans_tiers = self.tier_index(self.len() - 1) > 0;
+        let elem = self.tier_at_mut(0).pop_front();
+        self.len -= 1;
+
+        if self.is_empty() {
+            self.reset_offsets();
+        } else if spans_tiers {
+            if self.tier_at(0).is_empty() {
+                self.tier_head = self.physical_tier(1);
+                self.front_gap = 0;
+            } else {
+                self.front_gap += 1;
+            }
+        }
+
+        if self.auto_contract {
+            self.try_contract(self.len());
+        }
+
+        elem
+    }
+
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.len(),
+            "rotation by {} out of range for length {}",
+            mid,
+            self.len()
+        );
+
+        // move whichever side is shorter across the ends
+        let k = self.len() - mid;
+        if mid == 0 || k == 0 {
+            return;
+        }
+
+        if mid <= k {
+            self.rotate_front_to_back(mid);
+        } else {
+            self.rotate_back_to_front(k);
+        }
+    }
+
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(
+            k <= self.len(),
+            "rotation by {} out of range for length {}",
+            k,
+            self.len()
+        );
+
+        self.rotate_left(self.len() - k);
+    }
+
+    // a whole tier moves by swapping it into the free tier past the back, or by only moving the
+    // head once every tier is in use; a partial last tier is then rebalanced against it with at
+    // most half a tier of moves, so only the runs short of a tier go element by element
+    fn rotate_front_to_back(&mut self, mut count: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        // the partial first tier goes over as one run, which leaves the front on a tier boundary
+        let front_len = self.tier_at(0).len();
+        if self.front_gap > 0 && count >= front_len {
+            self.move_front_to_back(front_len);
+            count -= front_len;
+        }
+
+        while count >= tier_capacity {
+            let populated_tiers = self.populated_tiers();
+            let back_len = self.tier_at(populated_tiers - 1).len();
+            if populated_tiers < self.num_tiers() {
+                self.swap_physical_tiers(
+                    self.physical_tier(0),
+                    self.physical_tier(populated_tiers),
+                );
+            }
+
+            self.tier_head = self.physical_tier(1);
+            count -= tier_capacity;
+
+            if back_len < tier_capacity {
+                self.rebalance_back(populated_tiers - 2, back_len);
+            }
+        }
+
+        self.move_front_to_back(count);
+    }
+
+    fn rotate_back_to_front(&mut self, mut count: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        // the partial last tier goes over as one run, which leaves the back on a tier boundary
+        let back_len = self.tier_at(self.populated_tiers() - 1).len();
+        if back_len < tier_capacity && count >= back_len {
+            self.move_back_to_front(back_len);
+            count -= back_len;
+        }
+
+        while count >= tier_capacity {
+            let front_len = self.tier_at(0).len();
+            let last_tier = self.physical_tier(self.populated_tiers() - 1);
+            let before_head = self.physical_tier(self.num_tiers() - 1);
+            if last_tier != before_head {
+                self.swap_physical_tiers(last_tier, before_head);
+            }
+
+            self.tier_head = before_head;
+            count -= tier_capacity;
+
+            if front_len < tier_capacity {
+                self.rebalance_front(front_len);
+            }
+        }
+
+        self.move_back_to_front(count);
+    }
+
+    // `count` is no more than the first tier holds
+    fn move_front_to_back(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let spans_tiers = self.tier_index(self.len() - 1) > 0;
+        let mut run = Vec::with_capacity(count);
+        for _ in 0..count {
+            run.push(self.tier_at_mut(0).pop_front());
+        }
+        self.len -= count;
+
+        if spans_tiers {
+            if self.tier_at(0).is_empty() {
+                self.tier_head = self.physical_tier(1);
+                self.front_gap = 0;
+            } else {
+                self.front_gap += count;
+            }
+        }
+
+        // the run stays in the vector, so pushing it back must not grow it
+        if self.len() + self.front_gap + count > self.capacity() {
+            self.close_front_gap();
+        }
+
+        for elem in run {
+            self.push(elem);
+        }
+    }
+
+    // `count` is no more than the last tier holds
+    fn move_back_to_front(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let last_tier = self.populated_tiers() - 1;
+        let mut run = Vec::with_capacity(count);
+        for _ in 0..count {
+            run.push(self.tier_at_mut(last_tier).pop_back());
+        }
+        self.len -= count;
+
+        for elem in run {
+            self.push_front(elem);
+        }
+    }
+
+    // the tier at `tier_index` holds `tier_len` elements ahead of a full tier, so either it is
+    // topped up from that tier, or its short run trades places with the full tier's tail and
+    // the two tiers swap
+    fn rebalance_back(&mut self, tier_index: usize, tier_len: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        if tier_capacity - tier_len <= tier_len {
+            for _ in tier_len..tier_capacity {
+                let elem = self.tier_at_mut(tier_index + 1).pop_front();
+                self.tier_at_mut(tier_index).push_back(elem);
+            }
+        } else {
+            for _ in 0..tier_len {
+                let elem = self.tier_at_mut(tier_index).pop_back();
+                let elem = self.tier_at_mut(tier_index + 1).pop_push_front(elem);
+                self.tier_at_mut(tier_index).push_front(elem);
+            }
+
+            self.swap_physical_tiers(
+                self.physical_tier(tier_index),
+                self.physical_tier(tier_index + 1),
+            );
+        }
+    }
+
+    // the mirror of `rebalance_back`, for a full first tier ahead of one holding the `tier_len`
+    // elements the front gap leaves room for
+    fn rebalance_front(&mut self, tier_len: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        if tier_capacity - tier_len <= tier_len {
+            for _ in tier_len..tier_capacity {
+                let elem = self.tier_at_mut(0).pop_back();
+                self.tier_at_mut(1).push_front(elem);
+            }
+        } else {
+            for _ in 0..tier_len {
+                let elem = self.tier_at_mut(1).pop_front();
+                let elem = self.tier_at_mut(0).pop_push_back(elem);
+                self.tier_at_mut(1).push_back(elem);
+            }
+
+            self.swap_physical_tiers(self.physical_tier(0), self.physical_tier(1));
+        }
+    }
+
+    fn swap_physical_tiers(&mut self, a: usize, b: usize) {
+        let tier_size = Self::tier_size_bytes(self.tier_capacity());
+        unsafe {
+            ptr::swap_nonoverlapping(
+                self.raw_tier_ptr(a) as *mut u8,
+                self.raw_tier_ptr(b) as *mut u8,
+                tier_size,
+            );
+        }
+    }
+
+    pub fn into_vec_deque(mut self) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(self.len());
+
+        for i in 0..self.num_tiers() {
+            let tier = self.tier_at_mut(i);
+
+            while !tier.is_empty() {
+                deque.push_back(tier.pop_front());
+            }
+        }
+
+        self.len = 0;
+        deque
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let tier_capacity = self.tier_capacity();
+
+        // a vector that has not allocated yet must also be empty
+        if tier_capacity == 0 && self.is_empty() && self.tier_head == 0 && self.front_gap == 0 {
+            return Ok(());
+        }
+
+        if tier_capacity < 2 || !tier_capacity.is_power_of_two() {
+            return Err(format!(
+                "tier capacity {} is not a power of two of at least 2",
+                tier_capacity
+            ));
+        }
+
+        if self.front_gap >= tier_capacity || self.tier_head >= self.num_tiers() {
+            return Err(format!(
+                "ring offsets (head {}, gap {}) out of range for tier capacity {}",
+                self.tier_head, self.front_gap, tier_capacity
+            ));
+        }
+
+        let mut num_elements = 0;
+        let mut seen_partial_tier = false;
+
+        for i in 0..self.num_tiers() {
+            let tier = self.tier_at(i);
+
+            // the first tier gives up the slots in its front gap
+            let room = if i == 0 {
+                tier.capacity() - self.front_gap
+            } else {
+                tier.capacity()
+            };
+
+            if tier.len() > room {
+                return Err(format!(
+                    "tier {} tracks {} elements but can only hold {}",
+                    i,
+                    tier.len(),
+                    room
+                ));
+            }
+
+            if seen_partial_tier && !tier.is_empty() {
+                return Err(format!(
+                    "tier {} holds elements after a tier that is not full",
+                    i
+                ));
+            }
+
+            seen_partial_tier |= tier.len() < room;
+            num_elements += tier.len();
+        }
+
+        if num_elements != self.len() {
+            return Err(format!(
+                "len is {} but tiers hold {} elements",
+                self.len(),
+                num_elements
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn as_flat_ranges(&self) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+
+        for i in 0..self.num_tiers() {
+            let tier = self.tier(i);
+            if tier.is_empty() {
+                continue;
+            }
+
+            let offset = i * self.tier_capacity();
+            let (first, second) = tier.masked_ranges();
+
+            ranges.push(offset + first.start..offset + first.end);
+            if !second.is_empty() {
+                ranges.push(offset + second.start..offset + second.end);
+            }
+        }
+
+        ranges
+    }
+
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let old_len = self.len();
+        self.normalize();
+
+        // lay every tier out linearly and forget its elements for the duration of the
+        // extraction, so leaking the iterator can at worst leak elements but never double-drop
+        for tier in self.tiers_mut() {
+            tier.rotate_reset();
+            tier.clear_and_leak();
+        }
+        self.len = 0;
+
+        ExtractIf {
+            vec: self,
+            pred,
+            idx: 0,
+            del: 0,
+            old_len,
+        }
+    }
+
+    // only valid while the vector is normalized and tiers are rotated to start at slot 0
+    fn linear_slot_ptr(&self, rank: usize) -> *mut T {
+        let tier_ptr = self.raw_tier_ptr(self.tier_index(rank));
+        unsafe { Tier::slot_ptr(tier_ptr, self.mask(rank)) }
+    }
+
+    // tier headers sit between the tiers in the buffer, so the closest thing to a single slice is
+    // one run per tier, in rank order both across and within the tiers
+    pub fn make_contiguous(&mut self) {
+        self.normalize();
+
+        for tier in self.tiers_mut() {
+            tier.rotate_reset();
+        }
+    }
+
+    pub fn compact_tier(&mut self, tier: usize) {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        self.tier_at_mut(tier).rotate_reset();
+    }
+
+    pub fn replace_tier_capacity(&mut self, new_tier_capacity: usize) {
+        assert!(new_tier_capacity.is_power_of_two());
+        assert!(new_tier_capacity.ge(&2));
+        assert!(
+            new_tier_capacity
+                .checked_pow(2)
+                .is_none_or(|cap| cap >= self.len()),
+            "tier capacity {} cannot hold {} elements",
+            new_tier_capacity,
+            self.len()
+        );
+
+        assert!(
+            self.capacity_bound.is_none_or(|bound| new_tier_capacity
+                .checked_pow(2)
+                .is_none_or(|cap| cap >= bound)),
+            "tier capacity {} cannot hold the fixed capacity",
+            new_tier_capacity
+        );
+
+        let mut rebuilt = Self::with_tier_capacity(new_tier_capacity);
+        rebuilt.capacity_bound = self.capacity_bound;
+        rebuilt.auto_contract = self.auto_contract;
+
+        for i in 0..self.num_tiers() {
+            let tier = self.tier_at_mut(i);
+            while !tier.is_empty() {
+                rebuilt.push(tier.pop_front());
+            }
+        }
+
+        #[cfg(test)]
+        {
+            rebuilt.expansions = self.expansions;
+            rebuilt.moves = self.moves;
+        }
+
+        self.len = 0;
+        *self = rebuilt;
+    }
+
+    // capacity is always the square of the tier capacity, so balance is measured against the
+    // layout `with_capacity` would choose for the current length
+    pub fn is_balanced(&self) -> bool {
+        let ideal_tier_capacity =
+            Self::tier_capacity_for(self.len().max(4)).expect("len always has a valid layout");
+
+        self.tier_capacity() <= ideal_tier_capacity * 2
+    }
+
+    pub fn get_or_push_default(&mut self, index: usize) -> &mut T
+    where
+        T: Default,
+    {
+        assert!(
+            index <= self.len(),
+            "index {} is past the end of a vector of len {}",
+            index,
+            self.len()
+        );
+
+        if index == self.len() {
+            self.push(T::default());
+        }
+
+        &mut self[index]
+    }
+
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.position_by(|candidate, best| candidate > best)
+    }
+
+    pub fn argmin(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.position_by(|candidate, best| candidate < best)
+    }
+
+    // strict comparison keeps the first of several equal candidates
+    fn position_by<F: Fn(&T, &T) -> bool>(&self, replaces: F) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut best = 0;
+        for i in 1..self.len() {
+            if replaces(&self[i], &self[best]) {
+                best = i;
+            }
+        }
+
+        Some(best)
+    }
+
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if needle.len() > self.len() {
+            return None;
+        }
+
+        (0..=self.len() - needle.len()).find(|&start| {
+            needle
+                .iter()
+                .enumerate()
+                .all(|(offset, elem)| self[start + offset] == *elem)
+        })
+    }
+
+    pub fn swap_remove_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = self.pop();
+        if self.is_empty() {
+            return Some(last);
+        }
+
+        Some(std::mem::replace(&mut self[0], last))
+    }
+
+    /// # Safety
+    ///
+    /// Only the slots from `head` to `tail` (wrapping, masked by the tier capacity) are
+    /// initialized; every other slot may hold uninitialized memory and must not be read.
+    pub unsafe fn tier_raw(&self, tier: usize) -> (usize, usize, &[MaybeUninit<T>]) {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        self.tier_at(tier).raw_parts()
+    }
+
+    // counts element moves against the current layout, leaving out the one-off cost of an
+    // expansion when the vector is already full
+    pub fn estimate_insert_cost(&self, index: usize) -> usize {
+        assert!(index <= self.len());
+
+        if index == self.len() {
+            return 0;
+        }
+
+        let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+        let tier = self.tier_at(tier_index);
+
+        if !tier.is_full() {
+            return if tier_rank == 0 {
+                0
+            } else {
+                tier.len() - tier_rank
+            };
+        }
+
+        // popping the back of the target tier frees the slot the shift moves into
+        let shift = if tier_rank == 0 {
+            0
+        } else {
+            tier.len() - 1 - tier_rank
+        };
+
+        let last_tier_index = self.tier_index(self.len() - 1);
+        let cascade_end = (tier_index + 1..=last_tier_index)
+            .find(|&i| !self.tier_at(i).is_full())
+            .unwrap_or(last_tier_index + 1);
+
+        1 + shift + (cascade_end - tier_index)
+    }
+
+    pub fn dedup_all(&mut self)
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut elements = Vec::with_capacity(self.len());
+        for i in 0..self.num_tiers() {
+            let tier = self.tier_at_mut(i);
+            while !tier.is_empty() {
+                elements.push(tier.pop_front());
+            }
+        }
+        self.len = 0;
+        self.reset_offsets();
+
+        let mut seen = HashSet::with_capacity(elements.len());
+        for elem in elements {
+            if seen.insert(elem.clone()) {
+                self.push(elem);
+            }
+        }
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(Ord::cmp);
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    // sorts in a scratch buffer and refills the same tiers, so the capacity is kept; a panicking
+    // comparator leaves the vector empty
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        let mut elements = Vec::with_capacity(self.len());
+        self.drain_all_into(&mut elements);
+
+        elements.sort_by(compare);
+        self.refill(elements);
+    }
+
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(Ord::cmp);
+    }
+
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        let mut elements = Vec::with_capacity(self.len());
+        self.drain_all_into(&mut elements);
+
+        elements.sort_unstable_by(compare);
+        self.refill(elements);
+    }
+
+    // edits are sorted by rank against the original vector, so one sweep rebuilds it
+    pub fn apply_edits<I: IntoIterator<Item = Edit<T>>>(&mut self, edits: I) {
+        let edits: Vec<Edit<T>> = edits.into_iter().collect();
+        let len = self.len();
+
+        // validate the whole script up front so a bad one leaves the vector untouched
+        let mut consumed = 0;
+        let mut new_len = len;
+        for edit in edits.iter() {
+            let rank = edit.rank();
+            assert!(rank >= consumed, "edit at rank {} is out of order", rank);
+
+            let in_range = match edit {
+                Edit::Insert(..) => rank <= len,
+                Edit::Remove(_) | Edit::Replace(..) => rank < len,
+            };
+            assert!(
+                in_range,
+                "edit at rank {} out of range for a vector of len {}",
+                rank, len
+            );
+
+            match edit {
+                Edit::Insert(..) => new_len += 1,
+                Edit::Remove(_) => {
+                    consumed = rank + 1;
+                    new_len -= 1;
+                }
+                Edit::Replace(..) => consumed = rank + 1,
+            }
+        }
+
+        assert!(
+            self.capacity_bound.is_none_or(|bound| new_len <= bound),
+            "vector is fixed at a capacity of {} elements",
+            self.capacity_bound.unwrap_or_default()
+        );
+
+        let mut old = Vec::with_capacity(len);
+        self.drain_all_into(&mut old);
+
+        let mut old = old.into_iter();
+        let mut next = 0;
+        let mut elements = Vec::with_capacity(new_len);
+        for edit in edits {
+            let rank = edit.rank();
+            elements.extend(old.by_ref().take(rank - next));
+            next = rank;
+
+            match edit {
+                Edit::Insert(_, elem) => elements.push(elem),
+                Edit::Remove(_) => {
+                    old.next();
+                    next += 1;
+                }
+                Edit::Replace(_, elem) => {
+                    old.next();
+                    elements.push(elem);
+                    next += 1;
+                }
+            }
+        }
+        elements.extend(old);
+
+        self.grow_once_for(new_len);
+        self.refill(elements);
+    }
+
+    fn refill(&mut self, elements: Vec<T>) {
+        debug_assert!(self.is_empty() && elements.len() <= self.capacity());
+
+        for elem in elements {
+            self.push(elem);
+        }
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    // a write cursor trails the read cursor and each kept element is swapped down to it, so the
+    // duplicates collect at the back and a single truncate drops them and fixes up the tiers
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        if self.len() <= 1 {
+            return;
+        }
+
+        let mut write = 0;
+        for read in 1..self.len() {
+            let (curr, prev) = (self.slot_ptr(read), self.slot_ptr(write));
+
+            // `read` is always ahead of `write`, so the two never alias
+            if !same_bucket(unsafe { &mut *curr }, unsafe { &mut *prev }) {
+                write += 1;
+                if read != write {
+                    self.swap(read, write);
+                }
+            }
+        }
+
+        self.truncate(write + 1);
+    }
+
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut sorted: Vec<T> = self.into_vec_deque().into();
+        sorted.sort();
+
+        sorted
+    }
+
+    // non-empty tiers always form a prefix of the ring, so the last rank tells where it ends
+    pub fn populated_tiers(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.tier_index(self.len() - 1) + 1
+        }
+    }
+
+    // pairs of (tier len, number of tiers at that len), ordered by len
+    pub fn tier_load_histogram(&self) -> Vec<(usize, usize)> {
+        let mut histogram = BTreeMap::new();
+        for i in 0..self.num_tiers() {
+            *histogram.entry(self.tier(i).len()).or_insert(0) += 1;
+        }
+
+        histogram.into_iter().collect()
+    }
+
+    // expects the vector to already be sorted
+    pub fn insert_sorted_unique(&mut self, elem: T) -> bool
+    where
+        T: Ord,
+    {
+        match self.binary_search(&elem) {
+            Ok(_) => false,
+            Err(rank) => {
+                self.insert(rank, elem);
+                true
+            }
+        }
+    }
+
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|elem| elem.cmp(x))
+    }
+
+    pub fn binary_search_by_key<B, F>(&self, key: &B, mut f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.binary_search_by(|elem| f(elem).cmp(key))
+    }
+
+    // every tier but the last is full, so the tiers are searched by their first element and
+    // only the one tier that can hold the match is searched rank by rank
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (mut low, mut high) = (0, self.populated_tiers());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if f(&self.tier_at(mid)[0]) == Ordering::Greater {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        let Some(tier_index) = low.checked_sub(1) else {
+            return Err(0);
+        };
+
+        let tier = self.tier_at(tier_index);
+        let tier_start = self.tier_start(tier_index);
+
+        let (mut low, mut high) = (0, tier.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match f(&tier[mid]) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(tier_start + mid),
+            }
+        }
+
+        Err(tier_start + low)
+    }
+
+    // same two-level search as `binary_search_by`, for a predicate that holds on a prefix
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (mut low, mut high) = (0, self.populated_tiers());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if pred(&self.tier_at(mid)[0]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        let Some(tier_index) = low.checked_sub(1) else {
+            return 0;
+        };
+
+        let tier = self.tier_at(tier_index);
+        let (mut low, mut high) = (1, tier.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if pred(&tier[mid]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        self.tier_start(tier_index) + low
+    }
+
+    // first rank whose element is not less than `x`
+    pub fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|elem| elem < x)
+    }
+
+    // first rank whose element is greater than `x`
+    pub fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|elem| elem <= x)
+    }
+
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.iter().is_sorted()
+    }
+
+    pub fn remove_indices(&mut self, indices: &[usize]) -> Vec<T> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let Some(&first) = indices.first() else {
+            return Vec::new();
+        };
+        assert!(indices[indices.len() - 1] < self.len());
+
+        // everything from the first removal onwards is detached once and pushed back compacted
+        let mut detached = Vec::with_capacity(self.len() - first);
+        while self.len() > first {
+            detached.push(self.pop());
+        }
+
+        let mut removed = Vec::with_capacity(indices.len());
+        let mut to_remove = indices.into_iter().peekable();
+
+        for (rank, elem) in (first..).zip(detached.into_iter().rev()) {
+            if to_remove.next_if_eq(&rank).is_some() {
+                removed.push(elem);
+            } else {
+                self.push(elem);
+            }
+        }
+
+        removed
+    }
+
+    // only a hint, with no measured benefit for sequential or random tier access; it compiles to
+    // nothing on targets without a stable prefetch instruction
+    pub fn prefetch_tier(&self, tier: usize) {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+            const CACHE_LINE_BYTES: usize = 64;
+            // the header and the first ranks; a scan that carries on past them is left to the
+            // hardware prefetcher, which picks up a sequential stream on its own
+            const ELEMENT_LINES: usize = 2;
+
+            let tier = self.tier_at(tier);
+            let first_rank = tier.elements.as_ptr().wrapping_add(tier.masked_head()) as *const i8;
+
+            unsafe {
+                _mm_prefetch::<_MM_HINT_T0>(tier as *const Tier<T> as *const i8);
+                for line in 0..ELEMENT_LINES {
+                    _mm_prefetch::<_MM_HINT_T0>(first_rank.wrapping_add(line * CACHE_LINE_BYTES));
+                }
+            }
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The address is only meaningful until the next mutation of the vector, since inserts,
+    /// removals and expansion move elements between slots and tiers.
+    pub unsafe fn element_addr(&self, index: usize) -> *const T {
+        assert!(index < self.len());
+
+        let tier = self.tier_at(self.tier_index(index));
+        let masked_rank = tier.masked_rank(self.tier_rank(index));
+
+        tier.elements.as_ptr().add(masked_rank) as *const T
+    }
+
+    pub fn drain_all_into(&mut self, out: &mut Vec<T>) {
+        out.clear();
+        out.reserve(self.len());
+
+        for i in 0..self.num_tiers() {
+            self.tier_at_mut(i).drain_into(out);
+        }
+
+        self.len = 0;
+        self.reset_offsets();
+    }
+
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut elements = Vec::new();
+        self.drain_all_into(&mut elements);
+
+        elements
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut elements = Vec::with_capacity(self.len());
+        elements.extend(self.iter().cloned());
+
+        elements
+    }
+
+    pub fn compare_and_swap(&mut self, index: usize, expected: &T, new: T) -> Result<T, &T>
+    where
+        T: PartialEq,
+    {
+        if self[index] != *expected {
+            return Err(&self[index]);
+        }
+
+        Ok(std::mem::replace(&mut self[index], new))
+    }
+
+    pub fn slot_state(&self, tier: usize, buffer_index: usize) -> SlotState {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        self.tier_at(tier).slot_state(buffer_index)
+    }
+
+    pub fn update_range<R, F>(&mut self, range: R, mut f: F)
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(usize, &mut T),
+    {
+        let Range { mut start, end } = resolve_range(range, self.len());
+
+        // resolve each tier once and walk its ranks directly
+        while start < end {
+            let tier_index = self.tier_index(start);
+            let tier_rank = self.tier_rank(start);
+            let count = (self.tier_start(tier_index + 1) - start).min(end - start);
+            let tier = self.tier_at_mut(tier_index);
+
+            for offset in 0..count {
+                f(start + offset, &mut tier[tier_rank + offset]);
+            }
+
+            start += count;
+        }
+    }
+
+    // pairs of (rank, byte offset of the element from the start of the buffer)
+    pub fn as_index_map(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let tier_size = Self::tier_size_bytes(self.tier_capacity());
+        let elements_offset = Tier::<T>::elements_offset();
+
+        (0..self.len()).map(move |rank| {
+            let physical = self.physical_tier(self.tier_index(rank));
+            let masked_rank = self.tier(physical).masked_rank(self.tier_rank(rank));
+
+            let offset = physical * tier_size + elements_offset + masked_rank * size_of::<T>();
+            (rank, offset)
+        })
+    }
+
+    pub fn remove_reporting(&mut self, index: usize) -> Option<(T, usize)> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let elem = self.remove(index);
+        Some((elem, self.len()))
+    }
+
+    // grows straight to the final layout, rather than doubling once per expansion
+    fn grow_once_for(&mut self, additional: usize) {
+        let required_capacity = self
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
+
+        if self.capacity() < required_capacity {
+            let tier_capacity =
+                Self::tier_capacity_for(required_capacity.max(4)).expect("capacity overflow");
+            self.replace_tier_capacity(tier_capacity);
+
+            #[cfg(test)]
+            {
+                self.expansions += 1;
+            }
+        } else if self.capacity() - self.front_gap < required_capacity {
+            self.close_front_gap();
+        }
+    }
+
+    pub fn reserve_and_fill<F: FnMut(usize) -> T>(&mut self, additional: usize, mut f: F) {
+        self.grow_once_for(additional);
+
+        for _ in 0..additional {
+            let rank = self.len();
+            self.push(f(rank));
+        }
+    }
+
+    // fills whole tier tails with bulk copies instead of pushing element by element
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        if let Some(bound) = self.capacity_bound {
+            assert!(
+                self.len() + other.len() <= bound,
+                "vector is fixed at a capacity of {} elements",
+                bound
+            );
+        }
+        self.grow_once_for(other.len());
+
+        let mut remaining = other;
+        while !remaining.is_empty() {
+            let tier_index = self.tier_index(self.len());
+            let room = self.tier_start(tier_index + 1) - self.len();
+            let chunk = &remaining[..room.min(remaining.len())];
+            let copied = self.tier_at_mut(tier_index).extend_from_slice(chunk);
+
+            self.len += copied;
+            remaining = &remaining[copied..];
+        }
+    }
+
+    pub fn extend_exact<I: ExactSizeIterator<Item = T>>(&mut self, iter: I) {
+        self.grow_once_for(iter.len());
+
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+
+    // every slot of every tier in buffer order, so wrapped tiers show their gap explicitly
+    pub fn to_physical_vec(&self) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        let mut slots = Vec::with_capacity(self.capacity());
+        for i in 0..self.num_tiers() {
+            let tier = self.tier(i);
+            slots.extend((0..tier.capacity()).map(|slot| tier.get(slot).cloned()));
+        }
+
+        slots
+    }
+
+    // one row per tier, `_` marks a free slot, `h`/`t` mark the masked head and tail
+    pub fn debug_grid(&self) -> String
+    where
+        T: Debug,
+    {
+        let mut grid = String::new();
+        for i in 0..self.num_tiers() {
+            let tier = self.tier(i);
+            let (head, tail) = (tier.masked_head(), tier.masked_tail());
+
+            let _ = write!(grid, "{:>3}:", i);
+            for slot in 0..tier.capacity() {
+                let marker = match (slot == head, slot == tail) {
+                    (true, true) => "ht",
+                    (true, false) => "h",
+                    (false, true) => "t",
+                    (false, false) => "",
+                };
+
+                match tier.get(slot) {
+                    Some(elem) => {
+                        let _ = write!(grid, " {}{:?}", marker, elem);
+                    }
+                    None => {
+                        let _ = write!(grid, " {}_", marker);
+                    }
+                }
+            }
+            grid.push('\n');
+        }
+
+        grid
+    }
+
+    pub fn clone_minimal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut cloned = Self::with_capacity(self.len().max(4));
+        cloned.auto_contract = self.auto_contract;
+
+        for i in 0..self.len() {
+            cloned.push(self[i].clone());
+        }
+
+        cloned
+    }
+
+    // stable Rust cannot specialize `Clone`, so `Copy` elements get a single copy of the whole buffer
+    pub fn clone_bitwise(&self) -> Self
+    where
+        T: Copy,
+    {
+        if self.ptr.is_null() {
+            return self.clone();
+        }
+
+        let layout = Self::layout_for(self.tier_capacity())
+            .expect("memory layout for tier size should be valid");
+
+        // tier headers sit inline with their elements, so the copy keeps the ring exactly as it is
+        let buffer_ptr = unsafe { alloc(layout) };
+        if buffer_ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr, buffer_ptr, layout.size());
+        }
+
+        Self {
+            ptr: buffer_ptr,
+            tier_capacity: self.tier_capacity(),
+            len: self.len(),
+            auto_contract: self.auto_contract,
+            capacity_bound: self.capacity_bound,
+            tier_head: self.tier_head,
+            front_gap: self.front_gap,
+            marker: PhantomData,
+            #[cfg(test)]
+            expansions: 0,
+            #[cfg(test)]
+            moves: 0,
+        }
+    }
+
+    pub fn eq_by<F: FnMut(&T, &T) -> bool>(&self, other: &Self, mut cmp: F) -> bool {
+        self.len() == other.len() && (0..self.len()).all(|i| cmp(&self[i], &other[i]))
+    }
+
+    pub fn rank_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        (0..self.len()).find(|&i| self[i] == *value)
+    }
+
+    fn tier_span(&self, tier_index: usize) -> TierSpan {
+        TierSpan {
+            index: tier_index,
+            physical: self.physical_tier(tier_index),
+            start: self.tier_start(tier_index),
+            end: self.tier_start(tier_index + 1),
+        }
+    }
+
+    // spans holding the first and the last rank of a range
+    fn end_spans(&self, range: &Range<usize>) -> (TierSpan, TierSpan) {
+        // an empty range never reads its spans, and a vector that has not allocated has no tiers
+        if range.is_empty() {
+            let span = TierSpan {
+                index: 0,
+                physical: 0,
+                start: range.start,
+                end: range.start,
+            };
+            return (span, span);
+        }
+
+        let last = range.end.max(range.start + 1) - 1;
+        (
+            self.tier_span(self.tier_index(range.start)),
+            self.tier_span(self.tier_index(last)),
+        )
+    }
+
+    fn span_elem(&self, span: TierSpan, rank: usize) -> &T {
+        &self.tier(span.physical)[rank - span.start]
+    }
+
+    // goes through the slot pointer so references to other slots stay valid
+    fn span_ptr(&mut self, span: TierSpan, rank: usize) -> *mut T {
+        unsafe { Tier::slot_ptr(self.raw_tier_ptr(span.physical), rank - span.start) }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self, 0..self.len())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let len = self.len();
+        IterMut::new(self, 0..len)
+    }
+
+    // the contiguous runs backing the elements in rank order, two for a tier that wraps
+    pub fn segments(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.populated_tiers())
+            .map(|i| self.tier_at(i))
+            .flat_map(|tier| {
+                let (first, second) = tier.as_slices();
+                [first, second]
+            })
+            .filter(|segment| !segment.is_empty())
+    }
+
+    pub fn segments_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let populated_tiers = self.populated_tiers();
+        self.tiers_in_order_mut()
+            .take(populated_tiers)
+            .flat_map(|tier| {
+                let (first, second) = tier.as_mut_slices();
+                [first, second]
+            })
+            .filter(|segment| !segment.is_empty())
+    }
+
+    // starts at the tier holding the first rank instead of walking up from rank 0
+    pub fn iter_range<R: RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+        Iter::new(self, resolve_range(range, self.len()))
+    }
+
+    pub fn iter_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+        let range = resolve_range(range, self.len());
+        IterMut::new(self, range)
+    }
+
+    // Index can only hand out references, so borrowed windows come from these instead
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> TieredSlice<'_, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSlice {
+            vec: self,
+            start,
+            end,
+        }
+    }
+
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> TieredSliceMut<'_, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSliceMut {
+            vec: self,
+            start,
+            end,
+        }
+    }
+
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        Chunks {
+            vec: self,
+            start: 0,
+            end: self.len(),
+            size: chunk_size,
+        }
+    }
+
+    pub fn windows(&self, window_size: usize) -> Windows<'_, T> {
+        assert!(window_size > 0, "window size must be non-zero");
+        Windows {
+            vec: self,
+            start: 0,
+            size: window_size,
+        }
+    }
+
+    // goes through the raw tier pointer so references to other slots stay valid
+    fn slot_ptr(&self, rank: usize) -> *mut T {
+        assert!(rank < self.len());
+
+        let physical = self.physical_tier(self.tier_index(rank));
+        unsafe { Tier::slot_ptr(self.raw_tier_ptr(physical), self.tier_rank(rank)) }
+    }
+
+    pub fn set_auto_contract(&mut self, enabled: bool) {
+        self.auto_contract = enabled;
+    }
+
+    // inverse of `expand`: splits every populated tier in half and shrinks the buffer
+    pub(crate) fn try_contract(&mut self, num_entries: usize) {
+        // only contract well below capacity to cull repeated alloc/free of memory upon reinsertion/redeletion
+        if self.tier_capacity() < 4 || num_entries >= self.capacity() / 8 {
+            return;
+        }
+
+        self.normalize();
+
+        let curr_tier_capacity = self.tier_capacity();
+        let new_tier_capacity = curr_tier_capacity >> 1;
+
+        let curr_layout = Self::layout_for(curr_tier_capacity)
+            .expect("memory layout for current tier size should be valid");
+        let new_layout = Self::layout_for(new_tier_capacity)
+            .expect("memory layout for new tier size should be valid");
+
+        let populated_tiers = num_entries.div_ceil(curr_tier_capacity);
+
+        // walk backwards since every split pair sits at or after the tier it came from in memory
+        for i in (0..populated_tiers).rev() {
+            let old_tier_ptr = self.raw_tier_ptr(i);
+
+            let old_tier = unsafe { &mut *old_tier_ptr };
+            old_tier.rotate_reset();
+
+            let old_tier_len = old_tier.len();
+            let first_len = old_tier_len.min(new_tier_capacity);
+            let second_len = old_tier_len - first_len;
+            let read_ptr = old_tier.elements.as_mut_ptr();
+
+            let first_tier_ptr = self.raw_tier_ptr_from_capacity(2 * i, new_tier_capacity);
+            let second_tier_ptr = self.raw_tier_ptr_from_capacity(2 * i + 1, new_tier_capacity);
+
+            // move the back half first so the front half's source is never overwritten
+            unsafe {
+                let write_ptr = (*second_tier_ptr).elements.as_mut_ptr();
+                ptr::copy(read_ptr.add(first_len), write_ptr, second_len);
+
+                let write_ptr = (*first_tier_ptr).elements.as_mut_ptr();
+                ptr::copy(read_ptr, write_ptr, first_len);
+
+                (*first_tier_ptr).clear_and_leak();
+                (*first_tier_ptr).tail_forward_by(first_len);
+
+                (*second_tier_ptr).clear_and_leak();
+                (*second_tier_ptr).tail_forward_by(second_len);
+            }
+        }
+
+        // reallocate and assign new tier_capacity
+        self.ptr = unsafe { realloc(self.ptr, curr_layout, new_layout.size()) };
+        self.tier_capacity = new_tier_capacity;
+
+        // remaining tiers were never written by a split and must be cleared out
+        for i in (2 * populated_tiers)..new_tier_capacity {
+            self.tier_mut(i).clear_and_leak();
+        }
+    }
+}
+
+// steps a base pointer across the buffer so each yielded tier is disjoint from the others
+pub(crate) struct TiersMut<'a, T> {
+    ptr: *mut u8,
+    tier_capacity: usize,
+    tier_size: usize,
+    remaining: usize,
+    marker: PhantomData<&'a mut Tier<T>>,
+}
+
+impl<'a, T> Iterator for TiersMut<'a, T> {
+    type Item = &'a mut Tier<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let tier_ptr: *mut Tier<T> =
+            ptr::slice_from_raw_parts_mut(self.ptr, self.tier_capacity) as _;
+
+        self.ptr = self.ptr.wrapping_add(self.tier_size);
+        self.remaining -= 1;
+
+        Some(unsafe { &mut *tier_ptr })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for TiersMut<'_, T> {}
+
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut FlatTieredVec<T>,
+    pred: F,
+    idx: usize,
+    del: usize,
+    old_len: usize,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.idx < self.old_len {
+            let curr = self.vec.linear_slot_ptr(self.idx);
+            let matched = (self.pred)(unsafe { &mut *curr });
+            self.idx += 1;
+
+            if matched {
+                self.del += 1;
+                return Some(unsafe { ptr::read(curr) });
+            }
+
+            if self.del > 0 {
+                let dst = self.vec.linear_slot_ptr(self.idx - 1 - self.del);
+                unsafe { ptr::copy_nonoverlapping(curr, dst, 1) };
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<T, F> Drop for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // close the gap left by extracted elements over the part that was never visited
+        if self.del > 0 {
+            for i in self.idx..self.old_len {
+                let src = self.vec.linear_slot_ptr(i);
+                let dst = self.vec.linear_slot_ptr(i - self.del);
+
+                unsafe { ptr::copy_nonoverlapping(src, dst, 1) };
+            }
+        }
+
+        let new_len = self.old_len - self.del;
+        let tier_capacity = self.vec.tier_capacity();
+
+        for (i, tier) in self.vec.tiers_mut().enumerate() {
+            let tier_len = new_len.saturating_sub(i * tier_capacity).min(tier_capacity);
+            tier.tail_forward_by(tier_len);
+        }
+
+        self.vec.len = new_len;
+        if self.vec.auto_contract {
+            self.vec.try_contract(new_len);
+        }
+    }
+}
+
+pub struct Chunks<'a, T> {
+    vec: &'a FlatTieredVec<T>,
+    start: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = TieredSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let end = self.end.min(self.start + self.size);
+        let chunk = self.vec.slice(self.start..end);
+        self.start = end;
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.start).div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+// windows are views over the vector itself, so one straddling a tier boundary needs no buffer
+pub struct Windows<'a, T> {
+    vec: &'a FlatTieredVec<T>,
+    start: usize,
+    size: usize,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = TieredSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start + self.size > self.vec.len() {
+            return None;
+        }
+
+        let window = self.vec.slice(self.start..self.start + self.size);
+        self.start += 1;
+
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.vec.len() + 1).saturating_sub(self.start + self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+// resolves `range` against a sequence of `len` elements, panicking like slice indexing does
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "range starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end {} out of range for a vector of len {}",
+        end,
+        len
+    );
+
+    start..end
+}
+
+// a window of ranks over a borrowed vector, indexed from the start of the window
+pub struct TieredSlice<'a, T> {
+    vec: &'a FlatTieredVec<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> TieredSlice<'a, T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.vec.get(self.start + index)
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::new(self.vec, self.start..self.end)
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> TieredSlice<'a, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSlice {
+            vec: self.vec,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl<T> Clone for TieredSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TieredSlice<'_, T> {}
+
+impl<T> Index<usize> for TieredSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len());
+        &self.vec[self.start + index]
+    }
+}
+
+impl<'a, T> IntoIterator for TieredSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct TieredSliceMut<'a, T> {
+    vec: &'a mut FlatTieredVec<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> TieredSliceMut<'a, T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.vec.get(self.start + index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.vec.get_mut(self.start + index)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.vec, self.start..self.end)
+    }
+
+    pub fn as_slice(&self) -> TieredSlice<'_, T> {
+        TieredSlice {
+            vec: self.vec,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> TieredSlice<'_, T> {
+        self.as_slice().slice(range)
+    }
+
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> TieredSliceMut<'_, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSliceMut {
+            vec: self.vec,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl<T> Index<usize> for TieredSliceMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len());
+        &self.vec[self.start + index]
+    }
+}
+
+impl<T> IndexMut<usize> for TieredSliceMut<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.len());
+        &mut self.vec[self.start + index]
+    }
+}
+
+impl<'a, T> IntoIterator for TieredSliceMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new(self.vec, self.start..self.end)
+    }
+}
+
+pub struct Iter<'a, T> {
+    vec: &'a FlatTieredVec<T>,
+    front: usize,
+    back: usize,
+    front_span: TierSpan,
+    back_span: TierSpan,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn new(vec: &'a FlatTieredVec<T>, range: Range<usize>) -> Self {
+        let (front_span, back_span) = vec.end_spans(&range);
+        Self {
+            vec,
+            front: range.start,
+            back: range.end,
+            front_span,
+            back_span,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        if self.front == self.front_span.end {
+            self.front_span = self.vec.tier_span(self.front_span.index + 1);
+        }
+
+        let elem = self.vec.span_elem(self.front_span, self.front);
+        self.front += 1;
+
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        if self.back < self.back_span.start {
+            self.back_span = self.vec.tier_span(self.back_span.index - 1);
+        }
+
+        Some(self.vec.span_elem(self.back_span, self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec,
+            front: self.front,
+            back: self.back,
+            front_span: self.front_span,
+            back_span: self.back_span,
+        }
+    }
+}
+
+// every rank is yielded at most once, so the handed out references never alias
+pub struct IterMut<'a, T> {
+    vec: &'a mut FlatTieredVec<T>,
+    front: usize,
+    back: usize,
+    front_span: TierSpan,
+    back_span: TierSpan,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    fn new(vec: &'a mut FlatTieredVec<T>, range: Range<usize>) -> Self {
+        let (front_span, back_span) = vec.end_spans(&range);
+        Self {
+            vec,
+            front: range.start,
+            back: range.end,
+            front_span,
+            back_span,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        if self.front == self.front_span.end {
+            self.front_span = self.vec.tier_span(self.front_span.index + 1);
+        }
+
+        let elem = self.vec.span_ptr(self.front_span, self.front);
+        self.front += 1;
+
+        Some(unsafe { &mut *elem })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        if self.back < self.back_span.start {
+            self.back_span = self.vec.tier_span(self.back_span.index - 1);
+        }
+
+        let elem = self.vec.span_ptr(self.back_span, self.back);
+        Some(unsafe { &mut *elem })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+// the ranks one tier holds, so walking a range only resolves a tier when crossing into it
+#[derive(Clone, Copy)]
+struct TierSpan {
+    index: usize,
+    physical: usize,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> IntoIterator for &'a FlatTieredVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut FlatTieredVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for FlatTieredVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let back_tier = self.populated_tiers();
+        IntoIter {
+            vec: self,
+            front_tier: 0,
+            back_tier,
+        }
+    }
+}
+
+// pops straight out of the tiers, leaving emptied tiers behind at both ends
+pub struct IntoIter<T> {
+    vec: FlatTieredVec<T>,
+    front_tier: usize,
+    back_tier: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.vec.is_empty() {
+            return None;
+        }
+
+        while self.vec.tier_at(self.front_tier).is_empty() {
+            self.front_tier += 1;
+        }
+
+        self.vec.len -= 1;
+        Some(self.vec.tier_at_mut(self.front_tier).pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.vec.len(), Some(self.vec.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.vec.is_empty() {
+            return None;
+        }
+
+        while self.vec.tier_at(self.back_tier - 1).is_empty() {
+            self.back_tier -= 1;
+        }
+
+        self.vec.len -= 1;
+        Some(self.vec.tier_at_mut(self.back_tier - 1).pop_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // the tiers no longer form a full prefix, so the vector's own drop can't find the rest
+        for tier in self.vec.tiers_mut() {
+            while !tier.is_empty() {
+                tier.pop_front();
+            }
+        }
+
+        self.vec.len = 0;
+    }
+}
+
+// as with `get`, an indexed `&T` stays valid until the next `&mut self` call
+impl<T> Index<usize> for FlatTieredVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len());
+        &self.tier_at(self.tier_index(index))[self.tier_rank(index)]
+    }
+}
+
+impl<T> IndexMut<usize> for FlatTieredVec<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.len());
+
+        let tier_rank = self.tier_rank(index);
+        &mut self.tier_at_mut(self.tier_index(index))[tier_rank]
+    }
+}
+
+impl<T> Drop for FlatTieredVec<T> {
+    fn drop(&mut self) {
+        for _ in 0..self.len() {
+            self.pop();
+        }
+
+        if self.ptr.is_null() {
+            return;
+        }
+
+        unsafe {
+            dealloc(
+                self.ptr,
+                Self::layout_for(self.tier_capacity).expect("current layout should be valid"),
+            );
+        }
+    }
+}
+
+impl<T> Default for FlatTieredVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for FlatTieredVec<T>
+where
     T: Clone,
 {
     fn clone(&self) -> Self {
+        if self.ptr.is_null() {
+            let mut cloned = Self::new();
+            cloned.auto_contract = self.auto_contract;
+            return cloned;
+        }
+
         let layout = Self::layout_for(self.tier_capacity())
             .expect("memory layout for tier size should be valid");
 
-        let buffer_ptr = unsafe { alloc_zeroed(layout) };
+        let buffer_ptr = unsafe { alloc_zeroed(layout) };
+        if buffer_ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+
+        let mut cloned = Self {
+            ptr: buffer_ptr,
+            tier_capacity: self.tier_capacity(),
+            len: 0,
+            auto_contract: self.auto_contract,
+            capacity_bound: self.capacity_bound,
+            tier_head: 0,
+            front_gap: 0,
+            marker: PhantomData,
+            #[cfg(test)]
+            expansions: 0,
+            #[cfg(test)]
+            moves: 0,
+        };
+
+        for i in 0..self.len() {
+            cloned.push(self[i].clone());
+        }
+
+        cloned
+    }
+}
+
+// the alternate form also lists how full each tier is, in logical order
+impl<T: Debug> Debug for FlatTieredVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return f.debug_list().entries(self.iter()).finish();
+        }
+
+        let tier_lens: Vec<usize> = (0..self.num_tiers())
+            .map(|i| self.tier_at(i).len())
+            .collect();
+        f.debug_struct("FlatTieredVec")
+            .field("len", &self.len())
+            .field("tier_capacity", &self.tier_capacity())
+            .field("tier_lens", &tier_lens)
+            .field("elements", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<FlatTieredVec<U>> for FlatTieredVec<T> {
+    fn eq(&self, other: &FlatTieredVec<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for FlatTieredVec<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for FlatTieredVec<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for FlatTieredVec<T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for FlatTieredVec<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for FlatTieredVec<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T> FromIterator<T> for FlatTieredVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+
+        let mut tiered_vec = Self::with_capacity(lower_bound.max(4));
+        for elem in iter {
+            tiered_vec.push(elem);
+        }
+
+        tiered_vec
+    }
+}
+
+impl<T> Extend<T> for FlatTieredVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+
+        // a bounded vector must never grow past its bound, so leave it to push to reject
+        if self.capacity_bound.is_none() {
+            self.grow_once_for(lower_bound);
+        }
+
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+// sized once up front, so every element lands with a plain push and nothing cascades
+impl<T> From<Vec<T>> for FlatTieredVec<T> {
+    fn from(elements: Vec<T>) -> Self {
+        let mut tiered_vec = Self::with_capacity(elements.len().max(4));
+        tiered_vec.extend_exact(elements.into_iter());
+
+        tiered_vec
+    }
+}
+
+// serialized as a plain sequence, so the tier geometry is rebuilt to fit the element count
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for FlatTieredVec<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for FlatTieredVec<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for FlatTieredVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn error_on_non_power_of_two_size() {
+        let _t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_on_small_size() {
+        let _t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(1);
+    }
+
+    #[test]
+    fn no_error_on_correct_size() {
+        let size = 4;
+        let t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(size);
+        assert_eq!(t.len(), 0);
+        assert_eq!(t.capacity(), size * size);
+        assert_eq!(t.tier_capacity(), size);
+        assert!(t.is_empty());
+        assert!(!t.is_full());
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_capacity(4);
+        assert_eq!(4, t.capacity());
+        assert_eq!(2, t.tier_capacity());
+
+        t = FlatTieredVec::with_capacity(8);
+        assert_eq!(16, t.capacity());
+        assert_eq!(4, t.tier_capacity());
+
+        t = FlatTieredVec::with_capacity(128);
+        assert_eq!(256, t.capacity());
+        assert_eq!(16, t.tier_capacity());
+    }
+
+    #[test]
+    fn insert() {
+        let size = 4;
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(size);
+        assert_eq!(t.tier_capacity(), size);
+
+        for i in 0..size {
+            t.insert(i, i * 2);
+            assert_eq!(t.len(), i + 1);
+        }
+
+        for i in 0..size {
+            let result = t.get(i);
+            assert!(result.is_some());
+            assert_eq!(*result.unwrap(), i * 2);
+        }
+
+        assert_eq!(t.len(), size);
+        assert!(!t.is_empty());
+        assert!(!t.is_full());
+    }
+
+    #[test]
+    fn expand() {
+        let size = 4;
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(size);
+
+        for i in 0..size * size {
+            t.insert(i, i);
+            assert_eq!(*t.get(i).unwrap(), i);
+        }
+        assert_eq!(t.tier_capacity(), size);
+        assert_eq!(t.len(), size * size);
+        assert!(t.is_full());
+
+        t.insert(size * size, size * size);
+        assert_eq!(t.tier_capacity(), size * 2);
+        assert_eq!(t.len(), (size * size) + 1);
+        assert!(!t.is_full());
+
+        for i in 0..((size * size) + 1) {
+            let result = t.get(i);
+            assert!(result.is_some());
+            assert_eq!(*result.unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn get_across_tiers() {
+        let size = 4;
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(size);
+
+        for i in 0..100 {
+            t.push(i);
+        }
+        assert_eq!(t.tier_capacity(), size * 4);
+
+        for i in 0..t.len() {
+            assert_eq!(*t.get(i).unwrap(), i);
+            assert_eq!(t[i], i);
+
+            let tier_rank = i % t.tier_capacity();
+            assert_eq!(
+                *t.tier(i / t.tier_capacity())
+                    .get_by_rank(tier_rank)
+                    .unwrap(),
+                i
+            );
+        }
+
+        assert!(t.get(t.len()).is_none());
+        assert!(t.get(t.capacity()).is_none());
+
+        for i in 0..t.len() {
+            *t.get_mut(i).unwrap() += 1;
+        }
+
+        for i in 0..t.len() {
+            assert_eq!(t[i], i + 1);
+        }
+    }
+
+    #[test]
+    fn vec_deque_round_trip() {
+        let t: FlatTieredVec<usize> = (0..100).collect();
+        assert_eq!(t.len(), 100);
+
+        let deque = t.into_vec_deque();
+        assert_eq!(deque.len(), 100);
+        assert!(deque.iter().copied().eq(0..100));
+
+        let t: FlatTieredVec<usize> = deque.into_iter().collect();
+        assert_eq!(t.len(), 100);
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn vec_deque_moves_without_double_drop() {
+        let counter = std::rc::Rc::new(());
+
+        let t: FlatTieredVec<_> = (0..40).map(|_| counter.clone()).collect();
+        assert_eq!(std::rc::Rc::strong_count(&counter), 41);
+
+        let deque = t.into_vec_deque();
+        assert_eq!(std::rc::Rc::strong_count(&counter), 41);
+
+        drop(deque);
+        assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "requested capacity exceeds maximum allocation size for element type"
+    )]
+    fn error_on_oversized_allocation() {
+        let _t: FlatTieredVec<[u64; 512]> = FlatTieredVec::with_tier_capacity(1 << 40);
+    }
+
+    #[test]
+    fn tiers_mut() {
+        let size = 4;
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(size);
+        assert_eq!(t.tiers_mut().len(), size);
+
+        for (i, tier) in t.tiers_mut().enumerate() {
+            assert_eq!(tier.capacity(), size);
+
+            for j in 0..size {
+                tier.push_back(i * size + j);
+            }
+        }
+        t.len = size * size;
+
+        for tier in t.tiers_mut() {
+            tier.pop_front();
+            tier.push_back(usize::MAX);
+        }
+
+        for i in 0..size {
+            for j in 0..size - 1 {
+                assert_eq!(t[i * size + j], i * size + j + 1);
+            }
+            assert_eq!(t[i * size + size - 1], usize::MAX);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let mut t: FlatTieredVec<usize> = (0..40).collect();
+        assert_eq!(t.validate(), Ok(()));
+
+        t.len = 41;
+        assert_eq!(
+            t.validate(),
+            Err("len is 41 but tiers hold 40 elements".to_string())
+        );
+
+        t.tier_mut(0).pop_back();
+        t.len = 39;
+        assert_eq!(
+            t.validate(),
+            Err("tier 1 holds elements after a tier that is not full".to_string())
+        );
+
+        t.tier_mut(0).push_back(7);
+        t.len = 40;
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[test]
+    fn capacity_after_expand() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(t.capacity_after_expand(), 64);
+
+        for i in 0..t.capacity() {
+            t.push(i);
+        }
+        let predicted = t.capacity_after_expand();
+
+        t.push(16);
+        assert_eq!(t.capacity(), predicted);
+        assert_eq!(t.capacity_after_expand(), 256);
+    }
+
+    #[test]
+    fn as_flat_ranges() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert!(t.as_flat_ranges().is_empty());
+
+        for i in 0..6 {
+            t.push(i);
+        }
+        assert_eq!(t.as_flat_ranges(), vec![0..4, 4..6]);
+
+        // wrap the first tier so its elements occupy [1, 4) followed by [0, 1)
+        let tier = t.tier_mut(0);
+        let elem = tier.pop_front();
+        tier.push_back(elem);
+
+        let ranges = t.as_flat_ranges();
+        assert_eq!(ranges, vec![1..4, 0..1, 4..6]);
+        assert_eq!(ranges[0].len() + ranges[1].len(), t.tier(0).len());
+    }
+
+    #[test]
+    fn tier_capacity_for_large_inputs() {
+        assert_eq!(
+            FlatTieredVec::<usize>::tier_capacity_for(1 << 62),
+            Some(1 << 31)
+        );
+        assert_eq!(
+            FlatTieredVec::<usize>::tier_capacity_for((1 << 61) + 1),
+            Some(1 << 31)
+        );
+        assert_eq!(FlatTieredVec::<usize>::tier_capacity_for(1 << 63), None);
+        assert_eq!(
+            FlatTieredVec::<usize>::tier_capacity_for((1 << 62) + 1),
+            None
+        );
+        assert_eq!(FlatTieredVec::<usize>::tier_capacity_for(usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn with_capacity_overflow() {
+        let _t: FlatTieredVec<usize> = FlatTieredVec::with_capacity(usize::MAX);
+    }
+
+    #[test]
+    fn matches_vec_under_mixed_operations() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(256);
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        let mut v: Vec<usize> = Vec::new();
+
+        for i in 0..2_000 {
+            if v.is_empty() || rng.gen_ratio(2, 3) {
+                let index = rng.gen_range(0..=v.len());
+                t.insert(index, i);
+                v.insert(index, i);
+            } else {
+                let index = rng.gen_range(0..v.len());
+                assert_eq!(t.remove(index), v.remove(index));
+            }
+
+            assert_eq!(t.len(), v.len());
+        }
+
+        assert_eq!(t.validate(), Ok(()));
+        for (i, elem) in v.iter().enumerate() {
+            assert_eq!(t[i], *elem);
+        }
+    }
+
+    #[test]
+    fn compact_tier() {
+        let mut t: FlatTieredVec<usize> = (0..16).collect();
+        for i in 0..3 {
+            t.insert(0, 100 + i);
+        }
+        assert_ne!(t.tier(0).masked_head(), 0);
+
+        let other_heads: Vec<usize> = (1..t.num_tiers())
+            .map(|i| t.tier(i).masked_head())
+            .collect();
+
+        t.compact_tier(0);
+        assert_eq!(t.tier(0).masked_head(), 0);
+        assert_eq!(
+            (1..t.num_tiers())
+                .map(|i| t.tier(i).masked_head())
+                .collect::<Vec<_>>(),
+            other_heads
+        );
+
+        let expected = [102, 101, 100].into_iter().chain(0..16);
+        for (i, elem) in expected.enumerate() {
+            assert_eq!(t[i], elem);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "tier index 4 out of range for 4 tiers")]
+    fn compact_tier_out_of_range() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        t.compact_tier(4);
+    }
+
+    #[test]
+    fn remove_and_contract() {
+        let size = 16;
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(size);
+        t.set_auto_contract(true);
+
+        for i in 0..size * size {
+            t.push(i);
+        }
+        assert_eq!(t.capacity(), size * size);
+
+        while t.len() > size * size / 8 {
+            t.remove(0);
+        }
+        assert_eq!(t.tier_capacity(), size);
+        assert_eq!(t.capacity(), size * size);
+
+        // contract
+        t.remove(0);
+        assert_eq!(t.tier_capacity(), size / 2);
+        assert_eq!(t.capacity(), size * size / 4);
+        assert!(t.validate().is_ok());
+
+        let offset = size * size - t.len();
+        for i in 0..t.len() {
+            assert_eq!(t[i], offset + i);
+        }
+
+        while t.len() > 1 {
+            t.remove(t.len() / 2);
+        }
+        assert_eq!(t.tier_capacity(), 2);
+        assert!(t.validate().is_ok());
+        assert_eq!(t[0], offset);
+    }
+
+    #[test]
+    fn remove_without_auto_contract() {
+        let mut t: FlatTieredVec<usize> = (0..256).collect();
+        let tier_capacity = t.tier_capacity();
+
+        while t.len() > 1 {
+            t.remove(0);
+        }
+        assert_eq!(t.tier_capacity(), tier_capacity);
+        assert_eq!(t[0], 255);
+    }
+
+    #[test]
+    fn replace_tier_capacity() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        for i in 0..16 {
+            t.push(i);
+        }
+        assert_eq!(t.tier_capacity(), 4);
+
+        t.replace_tier_capacity(8);
+        assert_eq!(t.tier_capacity(), 8);
+        assert_eq!(t.len(), 16);
+        assert!(t.validate().is_ok());
+        for i in 0..16 {
+            assert_eq!(t[i], i);
+        }
+
+        t.replace_tier_capacity(4);
+        assert_eq!(t.tier_capacity(), 4);
+        assert!(t.validate().is_ok());
+        for i in 0..16 {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "tier capacity 2 cannot hold 16 elements")]
+    fn replace_tier_capacity_too_small() {
+        let mut t: FlatTieredVec<usize> = (0..16).collect();
+        t.replace_tier_capacity(2);
+    }
+
+    #[test]
+    fn is_balanced() {
+        let mut t: FlatTieredVec<usize> = (0..1000).collect();
+        assert!(t.is_balanced());
+
+        let mut grown: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        for i in 0..1000 {
+            grown.push(i);
+        }
+        assert!(grown.is_balanced());
+
+        t.replace_tier_capacity(128);
+        assert!(!t.is_balanced());
+
+        let mut skewed: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(64);
+        for i in 0..16 {
+            skewed.push(i);
+        }
+        assert!(!skewed.is_balanced());
+
+        skewed.replace_tier_capacity(4);
+        assert!(skewed.is_balanced());
+    }
+
+    #[test]
+    fn get_or_push_default() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        t.push(1);
+
+        *t.get_or_push_default(0) += 10;
+        assert_eq!(t[0], 11);
+        assert_eq!(t.len(), 1);
+
+        for i in 1..8 {
+            assert_eq!(*t.get_or_push_default(i), 0);
+            *t.get_or_push_default(i) = i;
+        }
+        assert_eq!(t.len(), 8);
+        for i in 1..8 {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is past the end of a vector of len 2")]
+    fn get_or_push_default_past_end() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        t.push(0);
+        t.push(1);
+        t.get_or_push_default(3);
+    }
+
+    #[test]
+    fn layout_sizes() {
+        fn check<T>() {
+            assert_eq!(FlatTieredVec::<T>::metadata_bytes(), size_of::<usize>() * 2);
+
+            for tier_capacity in [2, 4, 16, 256] {
+                let tier_size = FlatTieredVec::<T>::tier_size_bytes(tier_capacity);
+                assert_eq!(
+                    tier_size,
+                    Tier::<T>::layout_for(tier_capacity).unwrap().size()
+                );
+                assert!(
+                    tier_size
+                        >= FlatTieredVec::<T>::metadata_bytes() + size_of::<T>() * tier_capacity
+                );
+                assert_eq!(
+                    tier_size * tier_capacity,
+                    FlatTieredVec::<T>::layout_for(tier_capacity)
+                        .unwrap()
+                        .size()
+                );
+            }
+        }
+
+        check::<()>();
+        check::<u8>();
+        check::<u64>();
+        check::<[u16; 3]>();
+        check::<u128>();
+        check::<String>();
+    }
+
+    #[test]
+    fn argmax_argmin() {
+        let t: FlatTieredVec<i32> = [3, -2, 9, 4, 0, 7].into_iter().collect();
+        assert_eq!(t.argmax(), Some(2));
+        assert_eq!(t.argmin(), Some(1));
+
+        let ties: FlatTieredVec<i32> = [1, 5, 0, 5, 0, 2].into_iter().collect();
+        assert_eq!(ties.argmax(), Some(1));
+        assert_eq!(ties.argmin(), Some(2));
+
+        let empty: FlatTieredVec<i32> = FlatTieredVec::with_tier_capacity(2);
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.argmin(), None);
+    }
+
+    #[test]
+    fn find_subslice() {
+        let t: FlatTieredVec<usize> = (0..16).chain(0..16).collect();
+        assert_eq!(t.tier_capacity(), 8);
+
+        assert_eq!(t.find_subslice(&[6, 7, 8, 9]), Some(6));
+        assert_eq!(t.find_subslice(&[14, 15, 0, 1]), Some(14));
+        assert_eq!(t.find_subslice(&[3, 5]), None);
+        assert_eq!(t.find_subslice(&(0..33).collect::<Vec<_>>()), None);
+        assert_eq!(t.find_subslice(&[]), Some(0));
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        assert_eq!(empty.find_subslice(&[]), Some(0));
+        assert_eq!(empty.find_subslice(&[0]), None);
+    }
+
+    #[test]
+    fn swap_remove_front() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.swap_remove_front(), Some(0));
+        assert_eq!(t.len(), 9);
+        assert_eq!(t[0], 9);
+        for i in 1..9 {
+            assert_eq!(t[i], i);
+        }
+
+        let mut single: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        single.push(7);
+        assert_eq!(single.swap_remove_front(), Some(7));
+        assert!(single.is_empty());
+        assert_eq!(single.swap_remove_front(), None);
+    }
+
+    #[test]
+    fn tier_raw() {
+        let mut t: FlatTieredVec<u32> = (0..16).collect();
+        t.insert(0, 100);
+        t.insert(0, 101);
+
+        let (head, tail, slots) = unsafe { t.tier_raw(0) };
+        let mask = slots.len() - 1;
+        assert_ne!(head & mask, 0);
+
+        let saved: Vec<MaybeUninit<u32>> = slots.to_vec();
+        drop(t);
+
+        let restored: Vec<u32> = (0..tail.wrapping_sub(head))
+            .map(|rank| unsafe { saved[head.wrapping_add(rank) & mask].assume_init() })
+            .collect();
+        assert_eq!(restored, [101, 100, 0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "tier index 2 out of range for 2 tiers")]
+    fn tier_raw_out_of_range() {
+        let t: FlatTieredVec<u32> = FlatTieredVec::with_tier_capacity(2);
+        let _ = unsafe { t.tier_raw(2) };
+    }
+
+    #[test]
+    fn estimate_insert_cost() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(8);
+        for i in 0..32 {
+            t.push(i);
+        }
+
+        assert_eq!(t.estimate_insert_cost(t.len()), 0);
+
+        // one move out of tier 0, one per later full tier, one into the first empty tier
+        let front_cost = t.estimate_insert_cost(0);
+        assert_eq!(front_cost, 5);
+        assert!(front_cost <= t.num_tiers());
+
+        // the shift inside tier 1 dominates the cascade for a mid-tier insert
+        assert_eq!(t.estimate_insert_cost(10), 1 + 5 + 3);
+
+        t.push(32);
+        assert_eq!(t.estimate_insert_cost(33), 0);
+        assert_eq!(t.estimate_insert_cost(32), 0);
+        assert_eq!(t.estimate_insert_cost(0), 5);
+    }
+
+    #[test]
+    fn dedup_all() {
+        let mut t: FlatTieredVec<usize> = [1, 2, 1, 3, 2, 4].into_iter().collect();
+        t.dedup_all();
+
+        assert_eq!(t.len(), 4);
+        assert!(t.validate().is_ok());
+        for (i, elem) in [1, 2, 3, 4].into_iter().enumerate() {
+            assert_eq!(t[i], elem);
+        }
+
+        let mut strings: FlatTieredVec<String> = (0..100).map(|i| (i % 7).to_string()).collect();
+        strings.dedup_all();
+        assert_eq!(strings.len(), 7);
+        for i in 0..7 {
+            assert_eq!(strings[i], i.to_string());
+        }
+    }
+
+    #[test]
+    fn into_sorted_vec() {
+        let t: FlatTieredVec<String> = (0..50).map(|i| ((i * 37) % 50).to_string()).collect();
+
+        let mut expected: Vec<String> = (0..50).map(|i| i.to_string()).collect();
+        expected.sort();
+
+        let sorted = t.into_sorted_vec();
+        assert_eq!(sorted.len(), 50);
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn tier_load_histogram() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(8);
+        for i in 0..60 {
+            t.push(i);
+        }
+        for _ in 0..30 {
+            t.remove(3);
+        }
+
+        // 30 elements over tiers of 8: three full, one partial and four empty
+        assert_eq!(t.tier_load_histogram(), vec![(0, 4), (6, 1), (8, 3)]);
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.tier_load_histogram(), vec![(0, 4)]);
+    }
+
+    #[test]
+    fn insert_sorted_unique() {
+        let mut t: FlatTieredVec<usize> = (0..20).map(|i| i * 2).collect();
+
+        assert!(t.insert_sorted_unique(7));
+        assert_eq!(t.len(), 21);
+        assert_eq!(t[3], 6);
+        assert_eq!(t[4], 7);
+        assert_eq!(t[5], 8);
+
+        assert!(!t.insert_sorted_unique(8));
+        assert!(!t.insert_sorted_unique(7));
+        assert_eq!(t.len(), 21);
+
+        assert!(t.insert_sorted_unique(100));
+        assert!(t.insert_sorted_unique(1));
+        for i in 1..t.len() {
+            assert!(t[i - 1] < t[i]);
+        }
+
+        let mut empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        assert!(empty.insert_sorted_unique(5));
+        assert!(!empty.insert_sorted_unique(5));
+        assert_eq!(empty.len(), 1);
+    }
+
+    #[test]
+    fn remove_indices() {
+        let mut t: FlatTieredVec<usize> = (0..6).map(|i| i * 10).collect();
+
+        assert_eq!(t.remove_indices(&[4, 1, 3, 1]), vec![10, 30, 40]);
+        assert_eq!(t.len(), 3);
+        assert!(t.validate().is_ok());
+        assert_eq!(t[0], 0);
+        assert_eq!(t[1], 20);
+        assert_eq!(t[2], 50);
+
+        assert!(t.remove_indices(&[]).is_empty());
+        assert_eq!(t.len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_indices_out_of_range() {
+        let mut t: FlatTieredVec<usize> = (0..6).collect();
+        t.remove_indices(&[2, 6]);
+    }
+
+    #[test]
+    fn prefetch_tier() {
+        let t: FlatTieredVec<u64> = (0..1000).collect();
+        for tier in 0..t.num_tiers() {
+            t.prefetch_tier(tier);
+        }
+        assert_eq!(t[999], 999);
+    }
+
+    #[test]
+    #[should_panic(expected = "tier index 32 out of range for 32 tiers")]
+    fn prefetch_tier_out_of_range() {
+        let t: FlatTieredVec<u64> = (0..1000).collect();
+        t.prefetch_tier(32);
+    }
+
+    #[test]
+    fn element_addr() {
+        let mut t: FlatTieredVec<u64> = (0..100).collect();
+        t.insert(0, 1000);
+        t.remove(50);
+
+        for i in [0, 1, 15, 16, 50, 99] {
+            let expected = t.get(i).unwrap() as *const u64;
+            assert_eq!(unsafe { t.element_addr(i) }, expected);
+        }
+    }
+
+    #[test]
+    fn drain_all_into() {
+        let mut t: FlatTieredVec<String> = (0..30).map(|i| i.to_string()).collect();
+        let capacity = t.capacity();
+        let mut out = vec![String::from("stale")];
+
+        t.drain_all_into(&mut out);
+        assert!(t.is_empty());
+        assert_eq!(t.capacity(), capacity);
+        assert_eq!(out, (0..30).map(|i| i.to_string()).collect::<Vec<_>>());
+
+        for i in 0..10 {
+            t.insert(0, i.to_string());
+        }
+
+        t.drain_all_into(&mut out);
+        assert!(t.is_empty());
+        assert!(t.validate().is_ok());
+        assert_eq!(
+            out,
+            (0..10).rev().map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn snapshot_round_trip() {
+        let mut t: FlatTieredVec<u64> = FlatTieredVec::with_tier_capacity(8);
+        for i in (0..50).rev() {
+            t.push_front(i * 3);
+        }
+
+        let mut bytes = Vec::new();
+        t.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 4 + 3 * 8 + 50 * 8);
+
+        let restored = FlatTieredVec::<u64>::read_from(&mut bytes.as_slice()).unwrap();
+        assert_eq!(restored.validate(), Ok(()));
+        assert_eq!(restored.tier_capacity(), 8);
+        assert_eq!(restored, t);
+
+        let err = FlatTieredVec::<u32>::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let truncated = &bytes[..bytes.len() - 1];
+        let err = FlatTieredVec::<u64>::read_from(&mut &truncated[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let mut corrupt = bytes.clone();
+        corrupt[4 + 8] = 3;
+        let err = FlatTieredVec::<u64>::read_from(&mut corrupt.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let empty: FlatTieredVec<u64> = FlatTieredVec::new();
+        let mut bytes = Vec::new();
+        empty.write_to(&mut bytes).unwrap();
+        let restored = FlatTieredVec::<u64>::read_from(&mut bytes.as_slice()).unwrap();
+        assert_eq!(restored.capacity(), 0);
+        assert!(restored.is_empty());
+
+        let mut oversized = b"TVEC".to_vec();
+        for field in [8u64, 1 << 28, 0] {
+            oversized.extend_from_slice(&field.to_le_bytes());
+        }
+        let restored = FlatTieredVec::<u64>::read_from(&mut oversized.as_slice()).unwrap();
+        assert_eq!(restored.validate(), Ok(()));
+        assert_eq!(restored.capacity(), 4);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn with_len_zeroed() {
+        let mut t: FlatTieredVec<u32> = FlatTieredVec::with_len_zeroed(8, 37);
+        assert_eq!(t.len(), 37);
+        assert!(t.validate().is_ok());
+        for i in 0..37 {
+            assert_eq!(t[i], 0);
+        }
+
+        t.push(1);
+        t.insert(0, 2);
+        assert_eq!(t[0], 2);
+        assert_eq!(t[38], 1);
+
+        let full: FlatTieredVec<u32> = FlatTieredVec::with_len_zeroed(4, 16);
+        assert!(full.is_full());
+        assert!(full.validate().is_ok());
+    }
+
+    #[test]
+    fn compare_and_swap() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.compare_and_swap(6, &6, 60), Ok(6));
+        assert_eq!(t[6], 60);
+
+        assert_eq!(t.compare_and_swap(6, &6, 600), Err(&60));
+        assert_eq!(t[6], 60);
+        assert_eq!(t.len(), 10);
+    }
+
+    #[test]
+    fn slot_state() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..6 {
+            t.push(i);
+        }
+        t.insert(0, 6);
+
+        // the cascade pushes 3 onto the front of tier 1, wrapping it to [4, 5, n, 3]
+        assert_eq!(t.slot_state(0, 3), SlotState::Occupied);
+        assert_eq!(t.slot_state(1, 1), SlotState::Occupied);
+        assert_eq!(t.slot_state(1, 2), SlotState::Free);
+        assert_eq!(t.slot_state(1, 3), SlotState::Occupied);
+        assert_eq!(t.slot_state(2, 0), SlotState::Free);
+        assert_eq!(t.slot_state(3, 4), SlotState::OutOfRange);
+    }
+
+    #[test]
+    fn tier_accessors_follow_the_ring() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        t.extend(1..9);
+        t.push_front(0);
+
+        // the pushed element claims the last physical tier, which is now logical tier 0
+        assert_eq!(t.tier_head, 3);
+        let (head, tail, slots) = unsafe { t.tier_raw(0) };
+        let mask = slots.len() - 1;
+        assert_eq!(tail.wrapping_sub(head), 1);
+        assert_eq!(unsafe { slots[head & mask].assume_init_read() }, 0);
+        assert_eq!(t.slot_state(0, head & mask), SlotState::Occupied);
+        assert_eq!(
+            t.slot_state(0, head.wrapping_add(1) & mask),
+            SlotState::Free
+        );
+        t.prefetch_tier(0);
+
+        t.compact_tier(0);
+        let (head, _, _) = unsafe { t.tier_raw(0) };
+        assert_eq!(head & mask, 0);
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..9));
+    }
+
+    #[test]
+    fn update_range() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.insert(0, 9 - i);
+        }
+
+        let mut visited = Vec::new();
+        t.update_range(3..7, |rank, elem| {
+            visited.push(rank);
+            *elem *= 2;
+        });
+
+        assert_eq!(visited, [3, 4, 5, 6]);
+        for i in 0..10 {
+            let expected = if (3..7).contains(&i) { i * 2 } else { i };
+            assert_eq!(t[i], expected);
+        }
+
+        t.update_range(..=1, |_, elem| *elem += 100);
+        t.update_range(8.., |_, elem| *elem += 100);
+        t.update_range(5..5, |_, _| panic!("empty range should not visit"));
+        assert_eq!(t[0], 100);
+        assert_eq!(t[1], 101);
+        assert_eq!(t[2], 2);
+        assert_eq!(t[9], 109);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 11 out of range for a vector of len 10")]
+    fn update_range_out_of_bounds() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+        t.update_range(5..11, |_, _| {});
+    }
+
+    #[test]
+    fn as_index_map() {
+        let mut t: FlatTieredVec<u32> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..8 {
+            t.push(i);
+        }
+        t.insert(0, 100);
+        t.insert(0, 101);
+
+        let base = t.ptr as usize;
+        for (rank, offset) in t.as_index_map() {
+            assert_eq!(unsafe { t.element_addr(rank) } as usize, base + offset);
+        }
+
+        let tier_size = FlatTieredVec::<u32>::tier_size_bytes(t.tier_capacity());
+        let before: Vec<(usize, usize)> = t.as_index_map().collect();
+        assert_eq!(t.tier(0).masked_head(), 2);
+
+        t.compact_tier(0);
+        let after: Vec<(usize, usize)> = t.as_index_map().collect();
+
+        // tier 0 now starts at its first slot while every other tier keeps its offsets
+        let elements_offset = Tier::<u32>::elements_offset();
+        for &(rank, offset) in &after[..4] {
+            assert_eq!(offset, elements_offset + rank * size_of::<u32>());
+        }
+        assert_eq!(after[4..], before[4..]);
+        assert!(after[4..].iter().all(|&(_, offset)| offset >= tier_size));
+    }
+
+    #[test]
+    fn remove_reporting() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.remove_reporting(4), Some((4, 9)));
+        assert_eq!(t.remove_reporting(8), Some((9, 8)));
+        assert_eq!(t[4], 5);
+
+        assert_eq!(t.remove_reporting(8), None);
+        assert_eq!(t.len(), 8);
+    }
+
+    #[test]
+    fn reserve_and_fill() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        t.push(0);
+
+        t.reserve_and_fill(10000, |rank| rank * 2);
+        assert_eq!(t.len(), 10001);
+        assert_eq!(t.capacity(), 16384);
+        assert_eq!(t.expansions, 1);
+        assert!(t.validate().is_ok());
+        for i in 0..t.len() {
+            assert_eq!(t[i], i * 2);
+        }
+    }
+
+    #[test]
+    fn to_physical_vec() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..6 {
+            t.push(i);
+        }
+        t.insert(0, 6);
+        t.insert(0, 7);
+
+        // tier 0 wraps to head 2, tier 1 takes the two displaced elements on its front
+        assert_eq!(
+            t.to_physical_vec(),
+            [
+                Some(0),
+                Some(1),
+                Some(7),
+                Some(6),
+                Some(4),
+                Some(5),
+                Some(2),
+                Some(3),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn clone_minimal() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_capacity(4096);
+        for i in 0..10 {
+            t.push(i);
+        }
+        assert_eq!(t.capacity(), 4096);
+
+        let cloned = t.clone_minimal();
+        assert_eq!(cloned.capacity(), 16);
+        assert_eq!(cloned.tier_capacity(), 4);
+        assert_eq!(t.capacity(), 4096);
+        for i in 0..10 {
+            assert_eq!(cloned[i], i);
+        }
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(64);
+        assert_eq!(empty.clone_minimal().capacity(), 4);
+    }
+
+    #[test]
+    fn eq_by() {
+        let approx = |a: &f64, b: &f64| (a - b).abs() < 1e-6;
+
+        let a: FlatTieredVec<f64> = (0..20).map(|i| i as f64 / 3.0).collect();
+        let close: FlatTieredVec<f64> = (0..20).map(|i| i as f64 / 3.0 + 1e-9).collect();
+        let far: FlatTieredVec<f64> = (0..20).map(|i| i as f64 / 3.0 + 1e-3).collect();
+        let short: FlatTieredVec<f64> = (0..19).map(|i| i as f64 / 3.0).collect();
+
+        assert!(a.eq_by(&close, approx));
+        assert!(!a.eq_by(&far, approx));
+        assert!(!a.eq_by(&short, approx));
+    }
+
+    #[test]
+    fn fixed_capacity() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::fixed_capacity(10);
+        let capacity = t.capacity();
+        let buffer = t.ptr;
+
+        for i in 0..10 {
+            assert_eq!(t.try_insert(i / 2, i), Ok(()));
+        }
+        assert_eq!(t.len(), 10);
+
+        assert_eq!(
+            t.try_push(10),
+            Err(TieredVectorError::CapacityBound {
+                bound: 10,
+                elem: 10
+            })
+        );
+        assert_eq!(
+            t.try_insert(0, 11).map_err(|err| err.into_elem()),
+            Err(Some(11))
+        );
+        assert_eq!(t.len(), 10);
+
+        t.remove(0);
+        assert_eq!(t.try_push(12), Ok(()));
+        assert_eq!(t.try_push(13).map_err(|err| err.into_elem()), Err(Some(13)));
+
+        assert_eq!(t.capacity(), capacity);
+        assert_eq!(t.ptr, buffer);
+    }
+
+    #[test]
+    #[should_panic(expected = "vector is fixed at a capacity of 6 elements")]
+    fn fixed_capacity_push_past_bound() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::fixed_capacity(6);
+        for i in 0..7 {
+            t.push(i);
+        }
+    }
+
+    #[test]
+    fn contract_keeps_layout_square() {
+        let mut t: FlatTieredVec<u16> = FlatTieredVec::with_tier_capacity(32);
+        t.set_auto_contract(true);
+        for i in 0..1024 {
+            t.push(i);
+        }
+
+        while t.len() > 3 {
+            t.remove(t.len() / 3);
+            assert!(t.capacity() > t.len());
+        }
+
+        assert_eq!(t.tier_capacity(), 4);
+        assert_eq!(t.capacity(), t.tier_capacity().pow(2));
+        assert_eq!(t.tiers_mut().len(), t.tier_capacity());
+        assert!(t.tiers_mut().all(|tier| tier.capacity() == 4));
+        assert!(t.validate().is_ok());
+    }
+
+    #[test]
+    fn rank_of() {
+        let t: FlatTieredVec<usize> = (0..20).chain([7, 30]).collect();
+
+        assert_eq!(t.rank_of(&18), Some(18));
+        assert_eq!(t.rank_of(&30), Some(21));
+        assert_eq!(t.rank_of(&7), Some(7));
+        assert_eq!(t.rank_of(&99), None);
+    }
+
+    #[test]
+    fn extend_exact() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        t.push(0);
+
+        t.extend_exact(1..10001);
+        assert_eq!(t.len(), 10001);
+        assert_eq!(t.capacity(), 16384);
+        assert_eq!(t.expansions, 1);
+        assert!(t.validate().is_ok());
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn debug_grid() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..6 {
+            t.push(i);
+        }
+        t.remove(0);
+
+        let grid = t.debug_grid();
+        let rows: Vec<&str> = grid.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], "  0: 4 ht1 2 3");
+        assert_eq!(rows[1], "  1: _ h5 t_ _");
+        assert_eq!(rows[2], "  2: ht_ _ _ _");
+        assert_eq!(rows[3], "  3: ht_ _ _ _");
+    }
+
+    #[test]
+    fn populated_tiers() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(t.populated_tiers(), 0);
+
+        for i in 0..40 {
+            t.insert(i / 2, i);
+            if i % 3 == 0 {
+                t.remove(i / 4);
+            }
+
+            let non_empty = (0..t.num_tiers())
+                .filter(|&i| !t.tier(i).is_empty())
+                .count();
+            assert_eq!(t.populated_tiers(), non_empty);
+        }
+
+        while !t.is_empty() {
+            let populated = t.populated_tiers();
+            t.remove(0);
+
+            assert!((populated..t.num_tiers()).all(|i| t.tier(i).is_empty()));
+            assert!(t.validate().is_ok());
+        }
+        assert_eq!(t.populated_tiers(), 0);
+    }
+
+    #[test]
+    fn iter() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..20 {
+            t.insert(0, 19 - i);
+        }
+        t.remove(3);
+        t.insert(3, 3);
+
+        let mut iter = t.iter();
+        assert_eq!(iter.len(), 20);
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&19));
+        assert_eq!(iter.len(), 18);
+        assert!(iter.copied().eq(1..19));
+
+        assert!(t.iter().rev().copied().eq((0..20).rev()));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..20 {
+            t.insert(0, 19 - i);
+        }
+
+        let mut iter = t.iter_mut();
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        *first += 100;
+        *last += 100;
+        assert_eq!(iter.len(), 18);
+
+        for elem in t.iter_mut().rev() {
+            *elem *= 2;
+        }
+
+        assert_eq!(t[0], 200);
+        assert_eq!(t[19], 238);
+        assert!(t
+            .iter()
+            .skip(1)
+            .take(18)
+            .copied()
+            .eq((1..19).map(|i| i * 2)));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..20 {
+            t.insert(0, (19 - i).to_string());
+        }
+
+        let mut iter = t.clone().into_iter();
+        assert_eq!(iter.len(), 20);
+        assert_eq!(iter.next(), Some("0".to_string()));
+        assert_eq!(iter.next_back(), Some("19".to_string()));
+        assert_eq!(iter.next_back(), Some("18".to_string()));
+        assert_eq!(iter.len(), 17);
+        assert!(iter.eq((1..18).map(|i| i.to_string())));
+
+        // dropping part way through releases whatever was not yielded
+        let mut iter = t.clone().into_iter();
+        for _ in 0..5 {
+            iter.next();
+        }
+        iter.next_back();
+        drop(iter);
+
+        let mut visited = 0;
+        for elem in &t {
+            assert_eq!(*elem, visited.to_string());
+            visited += 1;
+        }
+        assert_eq!(visited, 20);
+
+        for elem in &mut t {
+            elem.push('!');
+        }
+        assert!(t
+            .into_iter()
+            .rev()
+            .eq((0..20).rev().map(|i| format!("{}!", i))));
+    }
+
+    #[test]
+    fn extend() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        t.push(0);
+
+        t.extend(1..1000);
+        assert_eq!(t.capacity(), 1024);
+        assert!(t.validate().is_ok());
+
+        // filtered iterators only report a lower bound of zero and grow as they go
+        t.extend((1000..1100).filter(|i| i % 2 == 0));
+        t.extend(&[7, 8, 9]);
+        assert_eq!(t.len(), 1053);
+        assert!(t.iter().take(1000).copied().eq(0..1000));
+        assert!(t
+            .iter()
+            .skip(1000)
+            .copied()
+            .eq((1000..1100).step_by(2).chain(7..10)));
+
+        let collected: FlatTieredVec<usize> = (0..100).collect();
+        assert!(collected.iter().copied().eq(0..100));
+    }
+
+    #[test]
+    fn truncate_and_clear() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..14 {
+            t.insert(0, (13 - i).to_string());
+        }
+
+        t.truncate(20);
+        assert_eq!(t.len(), 14);
+
+        t.truncate(6);
+        assert_eq!(t.len(), 6);
+        assert!(t.validate().is_ok());
+        assert!(t
+            .iter()
+            .eq((0..6).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert_eq!(t.slot_state(2, 0), SlotState::Free);
+
+        // a fully emptied tier is rewound so it starts from the first slot again
+        t.truncate(4);
+        assert_eq!(t.slot_state(1, 0), SlotState::Free);
+        t.push("4".to_string());
+        assert_eq!(t.slot_state(1, 0), SlotState::Occupied);
+
+        t.clear();
+        assert!(t.is_empty());
+        assert_eq!(t.populated_tiers(), 0);
+        t.push("again".to_string());
+        assert_eq!(t[0], "again");
+    }
+
+    #[test]
+    fn split_off() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..14 {
+            t.insert(0, (13 - i).to_string());
+        }
+
+        let mut aligned = t.clone();
+        let back = aligned.split_off(8);
+        assert_eq!(aligned.len(), 8);
+        assert_eq!(back.len(), 6);
+        assert!(aligned.validate().is_ok());
+        assert!(back.validate().is_ok());
+        assert!(aligned
+            .iter()
+            .eq((0..8).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert!(back
+            .iter()
+            .eq((8..14).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+
+        let back = t.split_off(5);
+        assert!(t
+            .iter()
+            .eq((0..5).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert!(back
+            .iter()
+            .eq((5..14).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+
+        assert!(t.split_off(5).is_empty());
+        let all = t.split_off(0);
+        assert!(t.is_empty());
+        assert_eq!(all.len(), 5);
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.swap_remove(2), 2);
+        assert_eq!(t.len(), 9);
+        assert_eq!(t[2], 9);
+
+        assert_eq!(t.swap_remove(8), 8);
+        assert_eq!(t.swap_remove(0), 0);
+        assert!(t.iter().copied().eq([7, 1, 9, 3, 4, 5, 6]));
+        assert!(t.validate().is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_remove_out_of_range() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+        t.swap_remove(10);
+    }
+
+    #[test]
+    fn swap() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.insert(0, (9 - i).to_string());
+        }
+
+        t.swap(1, 2);
+        t.swap(9, 0);
+        t.swap(5, 5);
+        t.swap(3, 6);
+        assert!(t
+            .iter()
+            .map(String::as_str)
+            .eq(["9", "2", "1", "6", "4", "5", "3", "7", "8", "0"]));
+        assert!(t.validate().is_ok());
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut t: FlatTieredVec<u64> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..5 {
+            t.insert(0, 4 - i);
+        }
+        t.remove(0);
+        t.insert(0, 0);
+
+        let src: Vec<u64> = (5..1000).collect();
+        t.extend_from_slice(&src[..3]);
+        t.extend_from_slice(&src[3..]);
+        t.extend_from_slice(&[]);
+
+        assert_eq!(t.len(), 1000);
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq(0..1000));
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_from_slice_past_bound() {
+        let mut t: FlatTieredVec<u64> = FlatTieredVec::fixed_capacity(6);
+        t.extend_from_slice(&[0; 7]);
+    }
+
+    #[test]
+    fn from_vec_and_slice() {
+        let t = FlatTieredVec::from((0..1000).collect::<Vec<usize>>());
+        assert_eq!(t.tier_capacity(), 32);
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq(0..1000));
+
+        let names = ["a".to_string(), "b".to_string(), "c".to_string()];
+        let t = FlatTieredVec::from_slice(&names);
+        assert_eq!(t.tier_capacity(), 2);
+        assert!(t.iter().eq(names.iter()));
+
+        let t: FlatTieredVec<usize> = Vec::new().into();
+        assert!(t.is_empty());
+    }
+
+    #[test]
+    fn into_vec_and_to_vec() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..14 {
+            t.insert(0, (13 - i).to_string());
+        }
+        t.remove(0);
+        t.insert(0, "0".to_string());
+
+        let expected: Vec<String> = (0..14).map(|i| i.to_string()).collect();
+        assert_eq!(t.to_vec(), expected);
+        assert_eq!(t.into_vec(), expected);
+
+        let empty: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        assert!(empty.into_vec().is_empty());
+    }
+
+    #[test]
+    fn push_front_and_pop_front() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..1000 {
+            t.push_front(i.to_string());
+        }
+
+        assert!(t.validate().is_ok());
+        assert!(t.iter().eq((0..1000)
+            .rev()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .iter()));
+
+        for i in (500..1000).rev() {
+            assert_eq!(t.pop_front(), i.to_string());
+        }
+        assert!(t.validate().is_ok());
+        assert_eq!(t.len(), 500);
+        assert_eq!(t[0], "499");
+
+        while !t.is_empty() {
+            t.pop_front();
+        }
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[test]
+    fn pop_front_then_push_at_capacity() {
+        for bound in [None, Some(256)] {
+            let mut t: FlatTieredVec<usize> = match bound {
+                Some(bound) => FlatTieredVec::fixed_capacity(bound),
+                None => FlatTieredVec::with_tier_capacity(16),
+            };
+            t.extend(0..255);
+
+            for i in 255..5_255 {
+                t.pop_front();
+                t.push(i);
+            }
+
+            if bound.is_none() {
+                // growing once leaves room behind every later gap
+                assert_eq!(t.expansions, 1);
+                assert_eq!(t.capacity(), 1024);
+                assert!(t.moves < 2 * 16);
+            } else {
+                // each pop opens one rank of gap, and closing a rank moves one element per tier
+                assert_eq!(t.capacity(), 256);
+                assert!(t.moves <= 5_000 * 15);
+            }
+            assert_eq!(t.validate(), Ok(()));
+            assert!(t.iter().copied().eq(5_000..5_255));
+        }
+    }
+
+    #[test]
+    fn matches_vec_deque_under_deque_operations() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
 
-        let mut cloned = Self {
-            ptr: buffer_ptr,
-            tier_capacity: self.tier_capacity(),
-            len: 0,
-            marker: PhantomData,
-        };
+        let mut rng = SmallRng::seed_from_u64(512);
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        let mut v: VecDeque<usize> = VecDeque::new();
+        t.set_auto_contract(true);
 
-        for i in 0..self.len() {
-            cloned.push(self[i].clone());
+        for i in 0..4_000 {
+            match rng.gen_range(0..7) {
+                0 | 1 => {
+                    t.push_front(i);
+                    v.push_front(i);
+                }
+                2 => {
+                    t.push(i);
+                    v.push_back(i);
+                }
+                3 => {
+                    let index = rng.gen_range(0..=v.len());
+                    t.insert(index, i);
+                    v.insert(index, i);
+                }
+                4 if !v.is_empty() => {
+                    assert_eq!(t.pop_front(), v.pop_front().unwrap());
+                }
+                5 => {
+                    t.extend_from_slice(&[i, i + 1, i + 2]);
+                    v.extend([i, i + 1, i + 2]);
+                }
+                _ if !v.is_empty() => {
+                    let index = rng.gen_range(0..v.len());
+                    assert_eq!(t.remove(index), v.remove(index).unwrap());
+                }
+                _ => {}
+            }
+
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.len(), v.len());
         }
 
-        return cloned;
+        assert!(t.iter().eq(v.iter()));
+        assert!(t.clone().into_iter().rev().eq(v.iter().rev().copied()));
+        assert_eq!(t.to_vec(), Vec::from(v.clone()));
+
+        let back = t.split_off(v.len() / 3);
+        let v_back = v.split_off(v.len() / 3);
+        assert!(t.iter().eq(v.iter()));
+        assert!(back.iter().eq(v_back.iter()));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn boundary_accessors() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert!(t.first().is_none() && t.last().is_none());
+        assert!(t.front_mut().is_none() && t.back_mut().is_none());
+
+        for i in 0..10 {
+            t.push(i);
+        }
+        t.push_front(100);
+
+        assert_eq!(t.front(), Some(&100));
+        assert_eq!(t.back(), Some(&9));
+
+        *t.first_mut().unwrap() += 1;
+        *t.last_mut().unwrap() += 1;
+        assert_eq!(t.first(), Some(&101));
+        assert_eq!(t.last(), Some(&10));
+        assert_eq!(t[t.len() - 1], 10);
+    }
 
     #[test]
-    #[should_panic]
-    fn error_on_non_power_of_two_size() {
-        let _t: FlatTieredVec<usize> = FlatTieredVec::new(5);
+    fn rotate_left_and_right() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        let mut v: Vec<usize> = Vec::new();
+        for i in 0..12 {
+            t.push(i);
+            v.push(i);
+        }
+
+        // twelve elements fill three tiers, so whole tiers move by re-linking
+        t.rotate_left(8);
+        v.rotate_left(8);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        t.rotate_right(5);
+        v.rotate_right(5);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        for i in 12..16 {
+            t.push(i);
+            v.push(i);
+        }
+
+        // the rotations left a front gap, so those pushes grew the vector; start again from a full one
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        t.extend(v.iter().copied());
+        assert!(t.is_full());
+        for k in [0, 1, 4, 7, 9, 16] {
+            t.rotate_left(k);
+            v.rotate_left(k);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+
+            t.rotate_right(k);
+            v.rotate_right(k);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+
+        t.pop_front();
+        v.remove(0);
+        for k in 0..=v.len() {
+            t.rotate_left(k);
+            v.rotate_left(k);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+        assert_eq!(t.capacity(), 16);
     }
 
     #[test]
-    #[should_panic]
-    fn error_on_small_size() {
-        let _t: FlatTieredVec<usize> = FlatTieredVec::new(1);
+    fn rotate_unaligned() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        let mut v: Vec<usize> = (0..13).collect();
+        t.extend(0..13);
+
+        // thirteen elements leave the last tier with one, so every moved tier is rebalanced
+        t.rotate_left(9);
+        v.rotate_left(9);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        for len in 9..=16 {
+            for front in 0..4 {
+                for k in 0..=len {
+                    let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+                    t.extend(front..len);
+                    for i in (0..front).rev() {
+                        t.push_front(i);
+                    }
+                    let mut v: Vec<usize> = (0..len).collect();
+
+                    t.rotate_left(k);
+                    v.rotate_left(k);
+                    assert_eq!(t.validate(), Ok(()));
+                    assert_eq!(t.to_vec(), v);
+
+                    t.rotate_right(k);
+                    v.rotate_right(k);
+                    assert_eq!(t.validate(), Ok(()));
+                    assert_eq!(t.to_vec(), v);
+                    assert_eq!(t.capacity(), 16);
+                }
+            }
+        }
     }
 
     #[test]
-    fn no_error_on_correct_size() {
-        let size = 4;
-        let t: FlatTieredVec<usize> = FlatTieredVec::new(size);
-        assert_eq!(t.len(), 0);
-        assert_eq!(t.capacity(), size * size);
-        assert_eq!(t.tier_capacity(), size);
-        assert!(t.is_empty());
-        assert!(!t.is_full());
+    #[should_panic(expected = "rotation by 3 out of range for length 2")]
+    fn rotate_left_past_len() {
+        let mut t: FlatTieredVec<usize> = (0..2).collect();
+        t.rotate_left(3);
     }
 
     #[test]
-    fn with_capacity() {
-        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_capacity(4);
-        assert_eq!(4, t.capacity());
-        assert_eq!(2, t.tier_capacity());
+    fn sort() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..40 {
+            t.push_front(((i * 37) % 40).to_string());
+        }
+        let capacity = t.capacity();
 
-        t = FlatTieredVec::with_capacity(8);
-        assert_eq!(16, t.capacity());
-        assert_eq!(4, t.tier_capacity());
+        let mut expected: Vec<String> = (0..40).map(|i| i.to_string()).collect();
+        expected.sort();
 
-        t = FlatTieredVec::with_capacity(128);
-        assert_eq!(256, t.capacity());
-        assert_eq!(16, t.tier_capacity());
+        t.sort();
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), expected);
+        assert_eq!(t.capacity(), capacity);
+
+        t.sort_unstable_by(|a, b| b.cmp(a));
+        expected.reverse();
+        assert_eq!(t.to_vec(), expected);
+
+        // stable sorting keeps equal keys in their current order
+        t.sort_by_key(|s| s.len());
+        assert_eq!(t[0], "9");
+        assert_eq!(t[9], "0");
+        assert_eq!(t[10], "39");
+        assert!(t.iter().map(String::len).is_sorted());
     }
 
     #[test]
-    fn insert() {
-        let size = 4;
-        let mut t: FlatTieredVec<usize> = FlatTieredVec::new(size);
-        assert_eq!(t.tier_capacity(), size);
+    fn binary_search() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i * 2);
+        }
+        t.pop_front();
+        assert!(t.is_sorted());
 
-        for i in 0..size {
-            t.insert(i, i * 2);
-            assert_eq!(t.len(), i + 1);
+        let v = t.to_vec();
+        for x in 0..62 {
+            assert_eq!(t.binary_search(&x), v.binary_search(&x));
         }
+        assert_eq!(t.binary_search_by_key(&29, |elem| elem / 2), Ok(28));
 
-        for i in 0..size {
-            let result = t.get(i);
-            assert!(result.is_some());
-            assert_eq!(*result.unwrap(), i * 2);
+        t.swap(0, 1);
+        assert!(!t.is_sorted());
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.binary_search(&3), Err(0));
+        assert!(empty.is_sorted());
+    }
+
+    #[test]
+    fn partition_point_and_bounds() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i / 3);
         }
+        t.pop_front();
 
-        assert_eq!(t.len(), size);
-        assert!(!t.is_empty());
-        assert!(!t.is_full());
+        let v = t.to_vec();
+        for x in 0..15 {
+            assert_eq!(
+                t.partition_point(|&elem| elem < x),
+                v.partition_point(|&elem| elem < x)
+            );
+            assert_eq!(t.lower_bound(&x), v.partition_point(|&elem| elem < x));
+            assert_eq!(t.upper_bound(&x), v.partition_point(|&elem| elem <= x));
+        }
+
+        let rank = t.upper_bound(&5);
+        t.insert(rank, 5);
+        assert!(t.is_sorted());
+        assert_eq!(t[rank - 1], 5);
+        assert_eq!(t[rank + 1], 6);
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.partition_point(|_| true), 0);
     }
 
     #[test]
-    fn expand() {
-        let size = 4;
-        let mut t: FlatTieredVec<usize> = FlatTieredVec::new(size);
+    fn dedup() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        let mut v: Vec<String> = Vec::new();
+        for i in 0..60 {
+            let elem = (i / 4 + i % 2).to_string();
+            t.push_front(elem.clone());
+            v.insert(0, elem);
+        }
 
-        for i in 0..size * size {
-            t.insert(i, i);
-            assert_eq!(*t.get(i).unwrap(), i);
+        t.dedup();
+        v.dedup();
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        t.dedup_by_key(|s| s.len());
+        v.dedup_by_key(|s| s.len());
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        // the closure sees the later element first, like `Vec::dedup_by`
+        let mut t: FlatTieredVec<usize> = [1, 2, 4, 5, 9, 10, 11].into_iter().collect();
+        t.dedup_by(|a, b| *a == *b + 1);
+        assert_eq!(t.to_vec(), [1, 4, 9, 11]);
+    }
+
+    #[test]
+    fn slices() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
         }
-        assert_eq!(t.tier_capacity(), size);
-        assert_eq!(t.len(), size * size);
-        assert!(t.is_full());
 
-        t.insert(size * size, size * size);
-        assert_eq!(t.tier_capacity(), size * 2);
-        assert_eq!(t.len(), (size * size) + 1);
-        assert!(!t.is_full());
+        let window = t.slice(5..20);
+        assert_eq!(window.len(), 15);
+        assert_eq!(window.get(0), Some(&5));
+        assert_eq!(window.get(15), None);
+        assert_eq!(window[14], 19);
+        assert!(window.iter().copied().eq(5..20));
 
-        for i in 0..((size * size) + 1) {
-            let result = t.get(i);
-            assert!(result.is_some());
-            assert_eq!(*result.unwrap(), i);
+        let inner = window.slice(2..=4);
+        assert!(inner.into_iter().copied().eq(7..10));
+        assert!(t.slice(..).iter().copied().eq(0..30));
+        assert!(t.slice(30..).is_empty());
+
+        let mut window = t.slice_mut(10..);
+        window[0] = 100;
+        *window.get_mut(19).unwrap() = 200;
+        assert_eq!(window.get_mut(20), None);
+        for elem in window.slice_mut(1..3) {
+            *elem += 1000;
+        }
+        assert_eq!(
+            window.slice(..4).iter().copied().collect::<Vec<_>>(),
+            [100, 1011, 1012, 13]
+        );
+
+        assert_eq!(t[10], 100);
+        assert_eq!(t[29], 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 5 out of range for a vector of len 3")]
+    fn slice_out_of_range() {
+        let t: FlatTieredVec<usize> = (0..10).collect();
+        let window = t.slice(2..5);
+        window.slice(1..5);
+    }
+
+    #[test]
+    fn iter_range() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i);
+        }
+
+        assert!(t.iter_range(3..29).copied().eq(3..29));
+        assert!(t.iter_range(3..29).rev().copied().eq((3..29).rev()));
+        assert!(t.iter_range(..=0).copied().eq(0..1));
+        assert!(t.iter_range(40..).next().is_none());
+
+        // both ends can meet in the middle of a tier
+        let mut iter = t.iter_range(5..9);
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&8));
+        assert_eq!(iter.len(), 2);
+        assert!(iter.copied().eq(6..8));
+
+        for elem in t.iter_range_mut(10..20) {
+            *elem *= 10;
+        }
+        for elem in t.iter_range_mut(30..).rev() {
+            *elem = 0;
+        }
+        assert_eq!(t[9], 9);
+        assert_eq!(t[10], 100);
+        assert_eq!(t[19], 190);
+        assert_eq!(t[20], 20);
+        assert!(t.iter_range(30..).all(|&elem| elem == 0));
+    }
+
+    #[test]
+    fn chunks_and_windows() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
+        }
+        let v = t.to_vec();
+
+        let chunks = t.chunks(7);
+        assert_eq!(chunks.len(), 5);
+        for (chunk, expected) in chunks.zip(v.chunks(7)) {
+            assert!(chunk.iter().eq(expected.iter()));
+        }
+
+        let windows = t.windows(5);
+        assert_eq!(windows.len(), 26);
+        for (window, expected) in windows.zip(v.windows(5)) {
+            assert!(window.iter().eq(expected.iter()));
+        }
+
+        let sums: Vec<usize> = t.windows(3).map(|w| w.iter().sum()).collect();
+        assert_eq!(sums[0], 3);
+        assert_eq!(sums[27], 84);
+
+        assert_eq!(t.windows(31).count(), 0);
+        assert_eq!(t.chunks(100).count(), 1);
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.chunks(3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_of_zero() {
+        let t: FlatTieredVec<usize> = (0..10).collect();
+        t.chunks(0);
+    }
+
+    #[test]
+    fn segments() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
+        }
+        t.remove(5);
+        t.insert(5, 5);
+
+        let segments: Vec<&[usize]> = t.segments().collect();
+        assert!(segments.iter().all(|segment| !segment.is_empty()));
+        assert!(segments.len() >= t.populated_tiers());
+        assert!(segments.concat().into_iter().eq(0..30));
+
+        for segment in t.segments_mut() {
+            for elem in segment.iter_mut() {
+                *elem *= 2;
+            }
+        }
+        assert!(t.iter().copied().eq((0..30).map(|i| i * 2)));
+
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.segments().count(), 0);
+    }
+
+    #[test]
+    fn make_contiguous() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
+        }
+        t.remove(3);
+        t.insert(3, 3);
+
+        t.make_contiguous();
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..30));
+
+        let segments: Vec<&[usize]> = t.segments().collect();
+        assert_eq!(segments.len(), t.populated_tiers());
+        assert_eq!(segments[0], &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let tier_size = FlatTieredVec::<usize>::tier_size_bytes(t.tier_capacity());
+        let first_offsets: Vec<usize> = t
+            .as_index_map()
+            .step_by(8)
+            .map(|(_, offset)| offset)
+            .collect();
+        assert!(first_offsets
+            .iter()
+            .enumerate()
+            .all(|(i, &offset)| offset == i * tier_size + first_offsets[0]));
+    }
+
+    #[test]
+    fn copy_within_and_move_range() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i);
+        }
+        t.remove(6);
+        t.insert(6, 6);
+        let mut v = t.to_vec();
+
+        for (src, dest) in [(3..20, 9), (9..26, 3), (0..40, 0), (30..40, 1), (5..5, 39)] {
+            t.copy_within(src.clone(), dest);
+            v.copy_within(src, dest);
+            assert_eq!(t.to_vec(), v);
+        }
+
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i.to_string());
+        }
+        let mut v = t.to_vec();
+
+        for (src, dest) in [
+            (3..20, 9),
+            (25..40, 0),
+            (0..1, 39),
+            (10..30, 10),
+            (12..13, 2),
+        ] {
+            t.move_range(src.clone(), dest);
+
+            let block: Vec<String> = v.drain(src).collect();
+            v.splice(dest..dest, block);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "destination 35 out of range for 10 ranks in a vector of len 40")]
+    fn move_range_past_end() {
+        let mut t: FlatTieredVec<usize> = (0..40).collect();
+        t.move_range(0..10, 35);
+    }
+
+    #[test]
+    fn swap_ranges() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i.to_string());
+        }
+        let mut v = t.to_vec();
+
+        for (a, b) in [
+            (0..10, 10..20),
+            (33..40, 3..10),
+            (5..6, 39..40),
+            (7..7, 8..8),
+        ] {
+            t.swap_ranges(a.clone(), b.clone());
+
+            let (low, high) = if a.start < b.start { (a, b) } else { (b, a) };
+            let (front, back) = v.split_at_mut(high.start);
+            front[low].swap_with_slice(&mut back[..high.len()]);
+
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "swapped ranges must not overlap")]
+    fn swap_overlapping_ranges() {
+        let mut t: FlatTieredVec<usize> = (0..40).collect();
+        t.swap_ranges(0..10, 5..15);
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..10).rev() {
+            t.push_front(i);
+        }
+
+        let evens: Vec<usize> = t.extract_if(|elem| *elem % 2 == 0).collect();
+        assert_eq!(evens, vec![0, 2, 4, 6, 8]);
+
+        assert_eq!(t.len(), 5);
+        assert_eq!(t.validate(), Ok(()));
+        for i in 0..t.len() {
+            assert_eq!(t[i], i * 2 + 1);
+        }
+    }
+
+    #[test]
+    fn extract_if_partially_consumed() {
+        let mut t: FlatTieredVec<String> = (0..100).map(|i| i.to_string()).collect();
+        t.set_auto_contract(true);
+
+        {
+            let mut extracted = t.extract_if(|elem| elem.len() == 1);
+            assert_eq!(extracted.next().as_deref(), Some("0"));
+            assert_eq!(extracted.next().as_deref(), Some("1"));
+        }
+
+        assert_eq!(t.len(), 98);
+        assert_eq!(t.validate(), Ok(()));
+        let expected: Vec<String> = (2..100).map(|i| i.to_string()).collect();
+        assert!(t.iter().eq(expected.iter()));
+
+        let short: Vec<String> = t
+            .extract_if(|elem| elem.len() < 2 || elem.ends_with('7'))
+            .collect();
+        assert_eq!(short.len(), 8 + 9);
+        assert_eq!(t.len(), 81);
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[test]
+    fn insert_slice_and_insert_iter() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(16);
+        t.extend(0..150);
+        let mut v = t.to_vec();
+
+        // forty elements at rank 20 fill the rest of the second tier and two whole tiers; the
+        // two tiers ahead are the shorter side, so they swap back into the free tiers past the
+        // head, and only the eight left over cascade through the eight tiers behind
+        let batch: Vec<usize> = (1000..1040).collect();
+        t.insert_slice(20, &batch);
+        v.splice(20..20, batch);
+
+        assert_eq!(t.tier_head, 14);
+        assert_eq!(t.moves, 12 + 8 * 8);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        // near the back the one tier behind is the shorter side and moves over instead; the
+        // three left over pass through it and push one element on into the next tier
+        t.insert_iter(160, 2000..2035);
+        v.splice(160..160, 2000..2035);
+
+        assert_eq!(t.tier_head, 14);
+        assert_eq!(t.moves, 76 + 16 + 3 + 1);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        let len = t.len();
+        t.insert_slice(len, &[1, 2, 3]);
+        t.insert_iter(3, std::iter::empty());
+        v.extend([1, 2, 3]);
+        assert_eq!(t.to_vec(), v);
+    }
+
+    #[test]
+    fn insert_slice_into_a_turned_ring() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(2786);
+        for bound in [None, Some(600)] {
+            let mut t: FlatTieredVec<String> = match bound {
+                Some(bound) => FlatTieredVec::fixed_capacity(bound),
+                None => FlatTieredVec::with_tier_capacity(2),
+            };
+            let mut v: VecDeque<String> = VecDeque::new();
+
+            for step in 0..400 {
+                match rng.gen_range(0..3) {
+                    0 => {
+                        t.push_front(step.to_string());
+                        v.push_front(step.to_string());
+                    }
+                    1 if !v.is_empty() => assert_eq!(Some(t.pop_front()), v.pop_front()),
+                    _ => {}
+                }
+
+                let len = rng.gen_range(0..24).min(600 - v.len());
+                let words: Vec<String> = (0..len).map(|i| format!("{}.{}", step, i)).collect();
+                let index = rng.gen_range(0..=v.len());
+                t.insert_slice(index, &words);
+                for (i, word) in words.into_iter().enumerate() {
+                    v.insert(index + i, word);
+                }
+
+                assert_eq!(t.validate(), Ok(()));
+                assert!(t.iter().eq(v.iter()));
+
+                if v.len() > 500 {
+                    t.truncate(50);
+                    v.truncate(50);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn apply_edits() {
+        let mut t: FlatTieredVec<String> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i.to_string());
+        }
+
+        t.apply_edits(vec![
+            Edit::Insert(0, "a".to_string()),
+            Edit::Remove(0),
+            Edit::Replace(5, "b".to_string()),
+            Edit::Insert(6, "c".to_string()),
+            Edit::Insert(6, "d".to_string()),
+            Edit::Remove(6),
+            Edit::Remove(7),
+            Edit::Insert(30, "e".to_string()),
+        ]);
+
+        let mut expected = vec!["a".to_string()];
+        expected.extend((1..5).map(|i| i.to_string()));
+        expected.extend(["b", "c", "d"].iter().map(|s| s.to_string()));
+        expected.extend((8..30).map(|i| i.to_string()));
+        expected.push("e".to_string());
+
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), expected);
+
+        let len = t.len();
+        t.apply_edits((0..len).map(Edit::Remove));
+        assert!(t.is_empty());
+
+        t.apply_edits((0..100).map(|i| Edit::Insert(0, i.to_string())));
+        assert_eq!(
+            t.to_vec(),
+            (0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "edit at rank 3 is out of order")]
+    fn apply_edits_out_of_order() {
+        let mut t: FlatTieredVec<usize> = (0..10).collect();
+        t.apply_edits(vec![Edit::Remove(3), Edit::Insert(3, 10)]);
+    }
+
+    #[test]
+    fn fallible_operations() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        assert_eq!(t.try_pop(), Err(TieredVectorError::Empty));
+        assert_eq!(
+            t.try_remove(0),
+            Err(TieredVectorError::IndexOutOfBounds {
+                index: 0,
+                len: 0,
+                elem: None
+            })
+        );
+
+        for i in 0..10 {
+            assert_eq!(t.try_push(i), Ok(()));
+        }
+        assert_eq!(
+            t.try_insert(11, 11),
+            Err(TieredVectorError::IndexOutOfBounds {
+                index: 11,
+                len: 10,
+                elem: Some(11)
+            })
+        );
+        assert_eq!(t.try_insert(10, 10), Ok(()));
+        assert_eq!(t.try_remove(3), Ok(3));
+        assert_eq!(t.try_pop(), Ok(10));
+        assert_eq!(t.to_vec(), vec![0, 1, 2, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 is past the end of a vector of len 3")]
+    fn insert_past_end() {
+        let mut t: FlatTieredVec<usize> = (0..3).collect();
+        t.insert(4, 4);
+    }
+
+    #[test]
+    fn debug() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        assert_eq!(format!("{:?}", t), "[]");
+
+        t.extend(0..3);
+        assert_eq!(format!("{:?}", t), "[0, 1, 2]");
+
+        let pretty = format!("{:#?}", t);
+        assert!(pretty.starts_with("FlatTieredVec {\n    len: 3,\n    tier_capacity: 2,\n"));
+        assert!(pretty.contains("tier_lens: [\n        2,\n        1,\n    ],"));
+    }
+
+    #[test]
+    fn equality() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+        t.extend(0..3);
+
+        let mut u: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..3).rev() {
+            u.push_front(i);
         }
+        assert_eq!(t, u);
+        assert_eq!(t, vec![0, 1, 2]);
+        assert_eq!(t, [0, 1, 2]);
+        assert_eq!(t, &[0, 1, 2][..]);
+
+        u.pop();
+        assert_ne!(t, u);
+        assert_ne!(t, vec![0, 1]);
+        assert_ne!(t, [0, 1, 3]);
+    }
+
+    #[test]
+    fn lazy_allocation() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::new();
+        assert_eq!(t.capacity(), 0);
+        assert!(t.ptr.is_null());
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.iter().count(), 0);
+        assert_eq!(t.clone(), t);
+        assert!(t.split_off(0).is_empty());
+
+        let predicted = t.capacity_after_expand();
+        t.push(0);
+        assert_eq!(t.capacity(), predicted);
+        assert_eq!(t.validate(), Ok(()));
+
+        let mut u: FlatTieredVec<usize> = Default::default();
+        u.push_front(0);
+        assert_eq!(t, u);
+
+        let mut u: FlatTieredVec<usize> = FlatTieredVec::new();
+        u.extend_from_slice(&[0, 1, 2, 3, 4]);
+        t.extend(1..5);
+        assert_eq!(t, u);
+        assert_eq!(t.validate(), Ok(()));
     }
 
-    // #[test]
-    // fn remove_and_contract() {
-    //     let size = 16;
-    //     let mut t: ImplicitTieredVec<usize> = ImplicitTieredVec::new(size);
-    //     assert_eq!(t.capacity(), size * size);
+    #[test]
+    fn clone_bitwise() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.push_front(i);
+        }
+        t.rotate_left(3);
+
+        let mut cloned = t.clone_bitwise();
+        assert_eq!(cloned.validate(), Ok(()));
+        assert_eq!(cloned, t);
+        assert_eq!(cloned.debug_grid(), t.debug_grid());
+
+        cloned.push(10);
+        cloned[0] = 11;
+        assert_eq!(t.len(), 10);
+        assert_ne!(t[0], 11);
 
-    //     for i in 0..size * size / 8 {
-    //         // size / 8 {
-    //         t.insert(i, i).is_ok();
-    //         assert_eq!(*t.get(i).unwrap(), i);
-    //     }
-    //     assert_eq!(t.tier_capacity(), size);
-    //     assert_eq!(t.len(), size * size / 8);
-    //     assert_eq!(t.capacity(), size * size);
+        let empty: FlatTieredVec<usize> = FlatTieredVec::new();
+        assert_eq!(empty.clone_bitwise().capacity(), 0);
+    }
 
-    //     assert!(t.remove(0).is_ok());
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut t: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(4);
+        for i in (0..10).rev() {
+            t.push_front(i);
+        }
 
-    //     assert_eq!(*t.get(0).unwrap(), 1);
-    //     assert_eq!(t.len(), (size * size / 8) - 1);
-    //     assert_eq!(t.capacity(), size * size);
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(json, "[0,1,2,3,4,5,6,7,8,9]");
 
-    //     // contract
-    //     assert!(t.remove(0).is_ok());
+        let u: FlatTieredVec<usize> = serde_json::from_str(&json).unwrap();
+        assert_eq!(u.validate(), Ok(()));
+        assert_eq!(u, t);
+        assert_eq!(u.capacity(), 16);
 
-    //     assert_eq!(*t.get(0).unwrap(), 2);
-    //     assert_eq!(t.len(), (size * size / 8) - 2);
-    //     assert_eq!(t.capacity(), size * size / 4);
-    // }
+        let empty: FlatTieredVec<usize> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+        assert!(serde_json::from_str::<FlatTieredVec<usize>>("[1,\"a\"]").is_err());
+    }
 }
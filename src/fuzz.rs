@@ -0,0 +1,85 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{FlatTieredVec, LinkedTieredVec};
+
+// besides the contents, the input picks how oversized the tiers are and how many elements
+// arrive through the front, which leaves the ring rotated and a gap in its first tier
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for FlatTieredVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut elements: Vec<T> = u.arbitrary()?;
+        if elements.is_empty() && u.arbitrary()? {
+            return Ok(Self::new());
+        }
+
+        let mut tiered_vec = Self::with_capacity(elements.len().max(4));
+        let oversize = u.int_in_range(0..=2)?;
+        if oversize > 0 {
+            tiered_vec.replace_tier_capacity(tiered_vec.tier_capacity() << oversize);
+        }
+        tiered_vec.set_auto_contract(u.arbitrary()?);
+
+        let pushed_front = u.int_in_range(0..=elements.len())?;
+        let back = elements.split_off(pushed_front);
+        tiered_vec.extend(back);
+        for elem in elements.into_iter().rev() {
+            tiered_vec.push_front(elem);
+        }
+
+        Ok(tiered_vec)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for LinkedTieredVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut elements: Vec<T> = u.arbitrary()?;
+        if elements.is_empty() && u.arbitrary()? {
+            return Ok(Self::new());
+        }
+
+        let mut tiered_vec = Self::with_capacity(elements.len().max(4));
+        let oversize = u.int_in_range(0..=2)?;
+        if oversize > 0 {
+            tiered_vec.replace_tier_capacity(tiered_vec.tier_capacity() << oversize);
+        }
+
+        let pushed_front = u.int_in_range(0..=elements.len())?;
+        let back = elements.split_off(pushed_front);
+        tiered_vec.extend(back);
+        for elem in elements.into_iter().rev() {
+            tiered_vec.push_front(elem);
+        }
+
+        Ok(tiered_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn arbitrary_vectors_are_valid() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut tier_capacities = Vec::new();
+
+        for _ in 0..200 {
+            let len = rng.gen_range(0..512);
+            let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+
+            let flat = FlatTieredVec::<u16>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            assert_eq!(flat.validate(), Ok(()));
+            assert_eq!(flat.iter().count(), flat.len());
+            tier_capacities.push(flat.tier_capacity());
+
+            let linked = LinkedTieredVec::<u16>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            assert_eq!(linked.validate(), Ok(()));
+            assert_eq!(linked.iter().count(), linked.len());
+        }
+
+        tier_capacities.sort_unstable();
+        tier_capacities.dedup();
+        assert!(tier_capacities.len() > 2);
+    }
+}
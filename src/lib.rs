@@ -1,7 +1,23 @@
 #![allow(dead_code)]
 
-mod flat;
-mod linked;
+#[cfg(feature = "rkyv")]
+mod archive;
+mod edit;
+mod error;
+pub mod flat;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+pub mod linked;
+mod sequence;
+mod slot;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 
-pub use flat::*;
-pub use linked::*;
+#[cfg(feature = "rkyv")]
+pub use archive::{ArchivedTieredVec, TieredVecResolver};
+pub use edit::Edit;
+pub use error::TieredVectorError;
+pub use flat::FlatTieredVec;
+pub use linked::LinkedTieredVec;
+pub use sequence::{Sequence, SequenceView, TieredSequence};
+pub use slot::SlotState;
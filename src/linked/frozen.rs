@@ -0,0 +1,119 @@
+use std::{
+    fmt::{self, Debug},
+    ops::Index,
+    sync::Arc,
+};
+
+use super::{Iter, LinkedTieredVec};
+
+// clones share the same tiers, so handing copies to other threads costs a reference count bump
+#[derive(Clone)]
+pub struct FrozenTieredVec<T> {
+    inner: Arc<LinkedTieredVec<T>>,
+}
+
+impl<T> FrozenTieredVec<T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    // only copies the elements when another snapshot still shares them
+    pub fn thaw(self) -> LinkedTieredVec<T>
+    where
+        T: Clone,
+    {
+        Arc::unwrap_or_clone(self.inner)
+    }
+}
+
+impl<T: Debug> Debug for FrozenTieredVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for FrozenTieredVec<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for FrozenTieredVec<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LinkedTieredVec::deserialize(deserializer).map(LinkedTieredVec::freeze)
+    }
+}
+
+impl<T> LinkedTieredVec<T> {
+    pub fn freeze(self) -> FrozenTieredVec<T> {
+        FrozenTieredVec {
+            inner: Arc::new(self),
+        }
+    }
+}
+
+impl<T> Index<usize> for FrozenTieredVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.inner[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::*;
+
+    #[test]
+    fn freeze_and_share() {
+        let t: LinkedTieredVec<usize> = (0..100).collect();
+        let frozen = t.freeze();
+
+        let handles: Vec<_> = (0..4)
+            .map(|offset| {
+                let frozen = frozen.clone();
+                thread::spawn(move || {
+                    assert_eq!(frozen.len(), 100);
+                    assert_eq!(frozen.get(offset), Some(&offset));
+                    frozen.iter().sum::<usize>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), (0..100).sum::<usize>());
+        }
+
+        let shared = frozen.clone();
+        let mut thawed = frozen.thaw();
+        thawed.push(100);
+        thawed[0] = 1000;
+
+        assert_eq!(thawed.len(), 101);
+        assert_eq!(shared.len(), 100);
+        assert_eq!(shared[0], 0);
+        assert!(shared.get(100).is_none());
+
+        let mut unique = shared.thaw();
+        unique.pop();
+        assert_eq!(unique.len(), 99);
+    }
+}
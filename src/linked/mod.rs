@@ -1,4 +1,6 @@
+mod frozen;
 pub(crate) mod tier;
 mod tiered_vec;
 
+pub use frozen::*;
 pub use tiered_vec::*;
@@ -1,8 +1,12 @@
 use std::{
+    collections::TryReserveError,
     mem::MaybeUninit,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
+    ptr, slice,
 };
 
+use crate::SlotState;
+
 pub struct Tier<T> {
     head: usize,
     tail: usize,
@@ -25,6 +29,27 @@ impl<T> Tier<T> {
         }
     }
 
+    pub fn try_new(capacity: usize) -> Result<Self, TryReserveError> {
+        assert!(capacity.is_power_of_two());
+
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(capacity)?;
+        unsafe {
+            vec.set_len(capacity);
+        }
+
+        Ok(Self {
+            elements: vec,
+            head: 0,
+            tail: 0,
+        })
+    }
+
+    // makes sure a later `merge` with a tier of `other_capacity` does not need to allocate
+    pub fn try_reserve_merge(&mut self, other_capacity: usize) -> Result<(), TryReserveError> {
+        self.elements.try_reserve_exact(other_capacity)
+    }
+
     #[inline]
     pub fn capacity(&self) -> usize {
         self.elements.len()
@@ -70,6 +95,22 @@ impl<T> Tier<T> {
         self.tail = self.tail.wrapping_sub(1);
     }
 
+    #[inline]
+    pub fn tail_forward_by(&mut self, extend_count: usize) {
+        self.tail = self.tail.wrapping_add(extend_count);
+    }
+
+    #[inline]
+    pub fn clear_and_leak(&mut self) {
+        self.head = 0;
+        self.tail = 0;
+    }
+
+    #[inline]
+    pub(crate) fn raw_parts(&self) -> (usize, usize, &[MaybeUninit<T>]) {
+        (self.head, self.tail, &self.elements)
+    }
+
     #[inline]
     pub(crate) fn masked_head(&self) -> usize {
         self.mask(self.head)
@@ -104,6 +145,16 @@ impl<T> Tier<T> {
         self.contains_masked_rank(self.masked_rank(rank))
     }
 
+    pub fn slot_state(&self, buffer_index: usize) -> SlotState {
+        if buffer_index >= self.capacity() {
+            SlotState::OutOfRange
+        } else if self.contains_masked_rank(buffer_index) {
+            SlotState::Occupied
+        } else {
+            SlotState::Free
+        }
+    }
+
     pub(crate) fn get(&self, idx: usize) -> Option<&T> {
         if !self.contains_masked_rank(idx) {
             return None;
@@ -130,6 +181,95 @@ impl<T> Tier<T> {
         self.get_mut(self.masked_rank(rank))
     }
 
+    pub(crate) fn masked_ranges(&self) -> (Range<usize>, Range<usize>) {
+        let masked_head = self.masked_head();
+        let end = masked_head + self.len();
+
+        if end <= self.capacity() {
+            (masked_head..end, 0..0)
+        } else {
+            (masked_head..self.capacity(), 0..end - self.capacity())
+        }
+    }
+
+    // live elements in rank order, split in two only where the ring wraps around
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.masked_ranges();
+        let elements = self.elements.as_ptr() as *const T;
+
+        unsafe {
+            (
+                slice::from_raw_parts(elements.add(first.start), first.len()),
+                slice::from_raw_parts(elements.add(second.start), second.len()),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.masked_ranges();
+        let (wrapped, rest) = self.elements.split_at_mut(first.start);
+
+        let first = &mut rest[..first.len()] as *mut [MaybeUninit<T>] as *mut [T];
+        let second = &mut wrapped[second] as *mut [MaybeUninit<T>] as *mut [T];
+
+        unsafe { (&mut *first, &mut *second) }
+    }
+
+    // yields elements in rank order, splitting the buffer so the references never overlap
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (first, second) = self.masked_ranges();
+        let (wrapped, rest) = self.elements.split_at_mut(first.start);
+
+        rest[..first.len()]
+            .iter_mut()
+            .chain(wrapped[second].iter_mut())
+            .map(|elem| unsafe { elem.assume_init_mut() })
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len() && b < self.len());
+
+        let (masked_a, masked_b) = (self.masked_rank(a), self.masked_rank(b));
+        self.elements.swap(masked_a, masked_b);
+    }
+
+    // copies as much of `src` as fits after the tail, returning how many elements were taken
+    pub fn extend_from_slice(&mut self, src: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        let count = src.len().min(self.capacity() - self.len());
+        let masked_tail = self.masked_tail();
+        let first_count = count.min(self.capacity() - masked_tail);
+
+        unsafe {
+            let dst = self.elements.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.add(masked_tail), first_count);
+            ptr::copy_nonoverlapping(src.as_ptr().add(first_count), dst, count - first_count);
+        }
+
+        self.tail_forward_by(count);
+        count
+    }
+
+    // moves every element onto the end of `out` with at most two bulk copies, leaving the tier empty
+    pub fn drain_into(&mut self, out: &mut Vec<T>) {
+        let (first, second) = self.masked_ranges();
+        let len = self.len();
+        out.reserve(len);
+
+        unsafe {
+            let src = self.elements.as_ptr() as *const T;
+            let dst = out.as_mut_ptr().add(out.len());
+
+            ptr::copy_nonoverlapping(src.add(first.start), dst, first.len());
+            ptr::copy_nonoverlapping(src.add(second.start), dst.add(first.len()), second.len());
+            out.set_len(out.len() + len);
+        }
+
+        self.clear_and_leak();
+    }
+
     pub fn rotate_reset(&mut self) {
         self.tail = self.len();
 
@@ -139,6 +279,14 @@ impl<T> Tier<T> {
         self.head = 0;
     }
 
+    #[inline]
+    pub(crate) fn slot_ptr(&mut self, masked_idx: usize) -> *mut T {
+        assert!(masked_idx < self.capacity());
+
+        // go through the buffer pointer so pointers to other slots are not invalidated
+        unsafe { self.elements.as_mut_ptr().add(masked_idx) as *mut T }
+    }
+
     #[inline]
     fn set_element(&mut self, masked_idx: usize, elem: T) -> &mut T {
         self.elements[masked_idx].write(elem)
@@ -212,6 +360,21 @@ impl<T> Tier<T> {
         self.replace_element(index, elem)
     }
 
+    // pops one element off the back for each element of `run` pushed onto the front, leaving
+    // the popped elements in `run` in their original order
+    pub fn pop_push_front_run(&mut self, run: &mut [T]) {
+        assert!(self.is_full());
+        assert!(run.len() <= self.capacity());
+
+        for elem in run.iter_mut().rev() {
+            self.head_backward();
+            self.tail_backward();
+            let index = self.masked_head();
+
+            std::mem::swap(elem, unsafe { self.elements[index].assume_init_mut() });
+        }
+    }
+
     fn shift_to_head(&mut self, from: usize) {
         let mut cursor: Option<T> = None;
         let mut i = from;
@@ -283,7 +446,7 @@ impl<T> Tier<T> {
         self.tail_backward();
         let mut i = self.masked_tail();
 
-        while i > gap_masked_idx {
+        while i != gap_masked_idx {
             if let Some(elem) = cursor {
                 cursor = Some(self.replace_element(i, elem));
             } else {
@@ -312,14 +475,15 @@ impl<T> Tier<T> {
             self.close_gap(masked_rank);
         }
 
-        return elem;
+        elem
     }
 
     pub fn merge(&mut self, mut other: Tier<T>) {
-        self.elements.reserve_exact(other.capacity());
-        unsafe {
-            self.elements.set_len(self.elements.capacity());
-        }
+        // elements must be laid out from slot 0 before the mask widens to the new capacity
+        self.rotate_reset();
+
+        let new_capacity = self.capacity() + other.capacity();
+        self.elements.resize_with(new_capacity, MaybeUninit::uninit);
 
         for _ in 0..other.len() {
             self.push_back(other.pop_front());
@@ -332,16 +496,15 @@ impl<T> Tier<T> {
         let new_capacity = self.capacity() / 2;
 
         let new_buffer = self.elements.split_off(new_capacity);
+        self.elements.shrink_to_fit();
         let remaining_tail = count.saturating_sub(new_capacity);
         self.tail = count.saturating_sub(remaining_tail);
 
-        let new_t = Tier {
+        Tier {
             elements: new_buffer,
             head: 0,
             tail: remaining_tail,
-        };
-
-        return new_t;
+        }
     }
 }
 
@@ -349,12 +512,26 @@ impl<T> Index<usize> for Tier<T> {
     type Output = T;
 
     fn index(&self, rank: usize) -> &Self::Output {
+        assert!(
+            rank < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            rank
+        );
+
         unsafe { self.elements[self.masked_rank(rank)].assume_init_ref() }
     }
 }
 
 impl<T> IndexMut<usize> for Tier<T> {
     fn index_mut(&mut self, rank: usize) -> &mut Self::Output {
+        assert!(
+            rank < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            rank
+        );
+
         let index = self.masked_rank(rank);
         unsafe { self.elements[index].assume_init_mut() }
     }
@@ -381,7 +558,7 @@ where
                     .clone(),
             );
 
-            i += 1;
+            i = i.wrapping_add(1);
         }
 
         Self {
@@ -417,6 +594,31 @@ mod tests {
         assert_eq!(t.capacity(), 4);
     }
 
+    #[test]
+    fn pop_push_front_run() {
+        let mut t: Tier<usize> = Tier::new(8);
+        for i in 0..8 {
+            t.push_back(i);
+        }
+        t.pop_front();
+        t.push_back(8);
+
+        // the tier has wrapped, so the run crosses the end of the buffer
+        let mut run = [20, 21, 22, 23, 24];
+        t.pop_push_front_run(&mut run);
+        assert_eq!(run, [4, 5, 6, 7, 8]);
+
+        let (front, back) = t.as_slices();
+        assert!(front
+            .iter()
+            .chain(back)
+            .copied()
+            .eq([20, 21, 22, 23, 24, 1, 2, 3]));
+
+        t.pop_push_front_run(&mut []);
+        assert_eq!(t.len(), 8);
+    }
+
     #[test]
     fn contains_rank() {
         let mut t: Tier<usize> = Tier::new(4);
@@ -703,4 +905,75 @@ mod tests {
         assert!(t.contains_masked_rank(2));
         assert!(t.contains_masked_rank(3));
     }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn index_empty_tier() {
+        let t: Tier<usize> = Tier::new(4);
+        let _ = t[0];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn index_past_len() {
+        let mut t: Tier<usize> = Tier::new(4);
+        t.push_back(0);
+        t.push_back(1);
+        assert_eq!(t[1], 1);
+
+        t[2] = 2;
+    }
+
+    #[test]
+    fn slot_state() {
+        let mut t: Tier<usize> = Tier::new(4);
+        assert_eq!(t.slot_state(0), SlotState::Free);
+        assert_eq!(t.slot_state(4), SlotState::OutOfRange);
+
+        // [0, 1, 2, n]
+        t.push_back(0);
+        t.push_back(1);
+        t.push_back(2);
+        assert_eq!(t.slot_state(0), SlotState::Occupied);
+        assert_eq!(t.slot_state(1), SlotState::Occupied);
+        assert_eq!(t.slot_state(3), SlotState::Free);
+
+        // [n, 1, 2, n] then wrapped to [3, 1, 2, n] once the tail passes the end
+        t.pop_front();
+        t.push_back(3);
+        assert_eq!(t.masked_head(), 1);
+        assert_eq!(t.masked_tail(), 0);
+        assert_eq!(t.slot_state(0), SlotState::Free);
+        assert_eq!(t.slot_state(3), SlotState::Occupied);
+
+        t.push_back(4);
+        t.pop_front();
+        assert_eq!(t.slot_state(0), SlotState::Occupied);
+        assert_eq!(t.slot_state(1), SlotState::Free);
+        assert_eq!(t.slot_state(2), SlotState::Occupied);
+        assert_eq!(t.slot_state(5), SlotState::OutOfRange);
+    }
+
+    #[test]
+    fn iter_mut_wrapped() {
+        let mut t: Tier<usize> = Tier::new(4);
+
+        // [2, 3, 0, 1]
+        t.push_back(0);
+        t.push_back(0);
+        t.pop_front();
+        t.pop_front();
+        t.push_back(0);
+        t.push_back(1);
+        t.push_back(2);
+        t.push_back(3);
+
+        for elem in t.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(
+            t.iter_mut().map(|elem| *elem).collect::<Vec<_>>(),
+            [0, 10, 20, 30]
+        );
+    }
 }
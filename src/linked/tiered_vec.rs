@@ -1,15 +1,55 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet, TryReserveError, VecDeque},
+    fmt::{self, Debug},
+    hash::Hash,
+    mem::{self, MaybeUninit},
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
+    ptr,
+};
 
 use super::tier::Tier;
+use crate::{Edit, SlotState, TieredVectorError};
 
 #[derive(Clone)]
 pub struct LinkedTieredVec<T> {
     tiers: Vec<Tier<T>>,
     len: usize,
+    capacity_bound: Option<usize>,
+
+    // the tiers form a ring starting at `tier_head`, and the first of them may leave
+    // `front_gap` ranks free at its front so elements can be pushed there without cascading
+    tier_head: usize,
+    front_gap: usize,
+
+    #[cfg(test)]
+    cascades: usize,
+    #[cfg(test)]
+    expansions: usize,
+    // elements carried from one tier into another
+    #[cfg(test)]
+    moves: usize,
 }
 
 impl<T> LinkedTieredVec<T> {
-    pub fn new(tier_capacity: usize) -> Self {
+    // allocates nothing until the first element arrives
+    pub const fn new() -> Self {
+        Self {
+            tiers: Vec::new(),
+            len: 0,
+            capacity_bound: None,
+            tier_head: 0,
+            front_gap: 0,
+            #[cfg(test)]
+            cascades: 0,
+            #[cfg(test)]
+            expansions: 0,
+            #[cfg(test)]
+            moves: 0,
+        }
+    }
+
+    pub fn with_tier_capacity(tier_capacity: usize) -> Self {
         assert!(tier_capacity.is_power_of_two());
         assert!(tier_capacity.ge(&2));
 
@@ -18,31 +58,87 @@ impl<T> LinkedTieredVec<T> {
             tiers.push(Tier::new(tier_capacity));
         }
 
-        Self { tiers, len: 0 }
+        Self::from_tiers(tiers)
     }
 
-    pub fn with_capacity(mut capacity: usize) -> Self {
-        assert!(capacity.ge(&4));
+    // hands back the allocation failure instead of aborting
+    pub(crate) fn try_with_tier_capacity(tier_capacity: usize) -> Result<Self, TryReserveError> {
+        let mut tiers = Vec::new();
+        tiers.try_reserve_exact(tier_capacity)?;
+        for _ in 0..tier_capacity {
+            tiers.push(Tier::try_new(tier_capacity)?);
+        }
 
-        if !capacity.is_power_of_two() {
-            capacity = capacity.next_power_of_two();
+        Ok(Self::from_tiers(tiers))
+    }
+
+    fn from_tiers(tiers: Vec<Tier<T>>) -> Self {
+        Self {
+            tiers,
+            len: 0,
+            capacity_bound: None,
+            tier_head: 0,
+            front_gap: 0,
+            #[cfg(test)]
+            cascades: 0,
+            #[cfg(test)]
+            expansions: 0,
+            #[cfg(test)]
+            moves: 0,
         }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity.ge(&4));
+
+        let tier_size = Self::tier_capacity_for(capacity).expect("capacity overflow");
+        Self::with_tier_capacity(tier_size)
+    }
+
+    pub fn from_slice(elements: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut tiered_vec = Self::with_capacity(elements.len().max(4));
+        tiered_vec.extend_exact(elements.iter().cloned());
+
+        tiered_vec
+    }
+
+    // never grows past `total_capacity`, so pushes and inserts at the bound are rejected
+    pub fn fixed_capacity(total_capacity: usize) -> Self {
+        let mut tiered_vec = Self::with_capacity(total_capacity.max(4));
+        tiered_vec.capacity_bound = Some(total_capacity);
+
+        tiered_vec
+    }
+
+    #[inline]
+    fn at_capacity_bound(&self) -> bool {
+        self.capacity_bound.is_some_and(|bound| self.len() >= bound)
+    }
+
+    pub(crate) fn tier_capacity_for(minimum_capacity: usize) -> Option<usize> {
+        let mut capacity = minimum_capacity.checked_next_power_of_two()?;
 
         let trailing = capacity.trailing_zeros();
         let shift_count = if trailing & 1 == 0 {
             trailing / 2
         } else {
-            capacity = capacity << 1;
-            (trailing + 1) / 2
+            capacity = capacity.checked_mul(2)?;
+            trailing.div_ceil(2)
         };
 
-        let tier_size = capacity >> shift_count;
-        let mut tiers = Vec::with_capacity(tier_size);
-        for _ in 0..tier_size {
-            tiers.push(Tier::new(tier_size));
+        Some(capacity >> shift_count)
+    }
+
+    pub fn from_iter_exact<I: ExactSizeIterator<Item = T>>(iter: I) -> Self {
+        let mut tiered_vec = Self::with_capacity(iter.len().max(4));
+        for elem in iter {
+            tiered_vec.push(elem);
         }
 
-        Self { tiers, len: 0 }
+        tiered_vec
     }
 
     #[inline]
@@ -60,6 +156,16 @@ impl<T> LinkedTieredVec<T> {
         self.tier_capacity().pow(2)
     }
 
+    #[inline]
+    pub fn capacity_after_expand(&self) -> usize {
+        // the first growth of a lazily created vector allocates two tiers of two
+        if self.tier_capacity() == 0 {
+            return 4;
+        }
+
+        (self.tier_capacity() << 1).pow(2)
+    }
+
     pub fn len(&self) -> usize {
         self.len
     }
@@ -69,9 +175,112 @@ impl<T> LinkedTieredVec<T> {
         val & (self.tier_capacity() - 1)
     }
 
+    // logical index of the tier holding `rank`, counted from `tier_head`
     #[inline]
     fn tier_index(&self, rank: usize) -> usize {
-        rank >> self.tier_capacity().ilog2()
+        (rank + self.front_gap) >> self.tier_capacity().ilog2()
+    }
+
+    #[inline]
+    fn tier_rank(&self, rank: usize) -> usize {
+        if rank + self.front_gap < self.tier_capacity() {
+            rank
+        } else {
+            self.mask(rank + self.front_gap)
+        }
+    }
+
+    // first rank held by the tier at logical index `tier_index`
+    #[inline]
+    fn tier_start(&self, tier_index: usize) -> usize {
+        (tier_index * self.tier_capacity()).saturating_sub(self.front_gap)
+    }
+
+    #[inline]
+    fn physical_tier(&self, tier_index: usize) -> usize {
+        (self.tier_head + tier_index) & (self.num_tiers() - 1)
+    }
+
+    #[inline]
+    fn tier_at(&self, tier_index: usize) -> &Tier<T> {
+        &self.tiers[self.physical_tier(tier_index)]
+    }
+
+    #[inline]
+    fn tier_at_mut(&mut self, tier_index: usize) -> &mut Tier<T> {
+        let physical = self.physical_tier(tier_index);
+        &mut self.tiers[physical]
+    }
+
+    fn tiers_in_order(&self) -> impl Iterator<Item = &Tier<T>> {
+        let (wrapped, rest) = self.tiers.split_at(self.tier_head);
+        rest.iter().chain(wrapped)
+    }
+
+    fn tiers_in_order_mut(&mut self) -> impl Iterator<Item = &mut Tier<T>> {
+        let (wrapped, rest) = self.tiers.split_at_mut(self.tier_head);
+        rest.iter_mut().chain(wrapped)
+    }
+
+    #[inline]
+    fn has_room_at_back(&self) -> bool {
+        self.len() + self.front_gap < self.capacity()
+    }
+
+    fn reset_offsets(&mut self) {
+        debug_assert!(self.is_empty());
+
+        self.tier_head = 0;
+        self.front_gap = 0;
+    }
+
+    // pulls every tier forward into the front gap, leaving the ring where it is
+    fn close_front_gap(&mut self) {
+        if self.front_gap == 0 {
+            return;
+        }
+
+        let populated_tiers = self.populated_tiers();
+
+        for i in 0..populated_tiers.saturating_sub(1) {
+            let moved = self.front_gap.min(self.tier_at(i + 1).len());
+
+            for _ in 0..moved {
+                let elem = self.tier_at_mut(i + 1).pop_front();
+                self.tier_at_mut(i).push_back(elem);
+            }
+
+            #[cfg(test)]
+            {
+                self.moves += moved;
+            }
+        }
+
+        self.front_gap = 0;
+    }
+
+    // closes the front gap and rotates the ring back to the first tier, which is the layout
+    // growing, shrinking and the bulk operations work on
+    fn normalize(&mut self) {
+        self.close_front_gap();
+
+        self.tiers.rotate_left(self.tier_head);
+        self.tier_head = 0;
+    }
+
+    // a front gap with no room left behind it only happens within a tier of full, so growing
+    // is cheaper than reclaiming the gap on every push; a bounded vector cannot grow, so it
+    // closes the gap in place at the cost of one move per tier
+    fn make_room_at_back(&mut self) {
+        if self.has_room_at_back() {
+            return;
+        }
+
+        if self.capacity_bound.is_none() {
+            self.expand();
+        } else {
+            self.close_front_gap();
+        }
     }
 
     #[inline]
@@ -84,20 +293,113 @@ impl<T> LinkedTieredVec<T> {
         self.len() == self.capacity()
     }
 
+    // a returned `&T` stays valid until the next `&mut self` call, since only those can move
+    // elements between tiers
     pub fn get(&self, rank: usize) -> Option<&T> {
-        self.tiers.get(self.tier_index(rank))?.get_by_rank(rank)
+        if rank >= self.len() {
+            return None;
+        }
+
+        self.tier_at(self.tier_index(rank))
+            .get_by_rank(self.tier_rank(rank))
     }
 
+    // the vector stays mutably borrowed for as long as the returned `&mut T` lives
     pub fn get_mut(&mut self, rank: usize) -> Option<&mut T> {
+        if rank >= self.len() {
+            return None;
+        }
+
         let tier_idx = self.tier_index(rank);
+        let tier_rank = self.tier_rank(rank);
+
+        self.tier_at_mut(tier_idx).get_by_rank_mut(tier_rank)
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
 
-        self.tiers.get_mut(tier_idx)?.get_by_rank_mut(rank)
+    // deque-style names for the same ends
+    pub fn front(&self) -> Option<&T> {
+        self.first()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.first_mut()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.last()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
+    }
+
+    // the tier count is derived from the length of `tiers`, so only the backing allocation grows
+    pub fn reserve_tiers(&mut self, additional_tiers: usize) {
+        self.tiers.reserve(additional_tiers);
     }
 
     fn expand(&mut self) {
+        self.try_grow().expect("allocation failed while expanding");
+    }
+
+    pub fn try_grow(&mut self) -> Result<(), TryReserveError> {
+        assert!(
+            self.capacity_bound.is_none(),
+            "cannot grow a fixed-capacity vector"
+        );
+
+        self.normalize();
+
+        // a lazily created vector gets its smallest layout on first growth
+        if self.tiers.is_empty() {
+            let mut tiers = Vec::new();
+            tiers.try_reserve_exact(2)?;
+            for _ in 0..2 {
+                tiers.push(Tier::try_new(2)?);
+            }
+
+            self.tiers = tiers;
+            return Ok(());
+        }
+
         let curr_tier_size = self.tier_capacity();
         let new_tier_size = self.tier_capacity() << 1;
 
+        // make every allocation up front so a failure leaves the vector untouched
+        self.tiers.try_reserve(new_tier_size - curr_tier_size)?;
+
+        for first_tier in self.tiers.iter_mut().step_by(2) {
+            first_tier.try_reserve_merge(curr_tier_size)?;
+        }
+
+        let num_new_tiers = new_tier_size - (curr_tier_size / 2);
+        let mut new_tiers = Vec::new();
+        new_tiers.try_reserve_exact(num_new_tiers)?;
+        for _ in 0..num_new_tiers {
+            new_tiers.push(Tier::try_new(new_tier_size)?);
+        }
+
+        #[cfg(test)]
+        {
+            self.expansions += 1;
+        }
+
         for i in 0..(curr_tier_size / 2) {
             let second_tier = self.tiers.remove(i + 1);
             let first_tier = &mut self.tiers[i];
@@ -105,14 +407,15 @@ impl<T> LinkedTieredVec<T> {
             first_tier.merge(second_tier);
         }
 
-        for _ in 0..(new_tier_size - (curr_tier_size / 2)) {
-            self.tiers.push(Tier::new(new_tier_size));
-        }
+        self.tiers.append(&mut new_tiers);
+        Ok(())
     }
 
-    fn try_contract(&mut self, num_entries: usize) {
+    pub(crate) fn try_contract(&mut self, num_entries: usize) {
         // only contract well below capacity to cull repeated alloc/free of memory upon reinsertion/redeletion
         if num_entries < self.capacity() / 8 {
+            self.normalize();
+
             let new_tier_size = self.tier_capacity() >> 1;
             let _ = self.tiers.split_off(new_tier_size >> 1);
 
@@ -126,237 +429,4861 @@ impl<T> LinkedTieredVec<T> {
             }
 
             assert_eq!(self.tiers.len(), new_tier_size);
+
+            // `split_off` keeps the old allocation, which is four times what is needed now
+            self.tiers.shrink_to_fit();
         }
     }
 
-    pub fn insert(&mut self, index: usize, elem: T) {
-        assert!(index <= self.len());
+    pub fn reserve(&mut self, additional: usize) {
+        let required_capacity = self
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
 
-        if self.is_full() {
+        while self.capacity() < required_capacity {
             self.expand();
         }
+    }
+
+    pub fn insert(&mut self, index: usize, elem: T) {
+        if let Err(err) = self.try_insert(index, elem) {
+            panic!("{}", err);
+        }
+    }
+
+    pub fn try_insert(&mut self, index: usize, elem: T) -> Result<(), TieredVectorError<T>> {
+        if index > self.len() {
+            return Err(TieredVectorError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+                elem: Some(elem),
+            });
+        }
+        if self.at_capacity_bound() {
+            return Err(TieredVectorError::CapacityBound {
+                bound: self.len(),
+                elem,
+            });
+        }
+
+        // appending never needs to displace elements into later tiers
+        if index == self.len() {
+            self.push(elem);
+            return Ok(());
+        }
+
+        self.make_room_at_back();
 
         let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+
+        // the first tier grows into its front gap rather than displacing into the next tier
+        if tier_index == 0 && self.front_gap > 0 {
+            let tier = self.tier_at_mut(0);
+            tier.insert(tier_rank, elem);
 
-        if !self.tiers[tier_index].is_full() {
-            self.tiers[tier_index].insert(index, elem);
+            let tier_len = tier.len();
+            self.front_gap = self.front_gap.min(self.tier_capacity() - tier_len);
             self.len += 1;
 
-            return;
+            return Ok(());
+        }
+
+        if !self.tier_at(tier_index).is_full() {
+            self.tier_at_mut(tier_index).insert(tier_rank, elem);
+            self.len += 1;
+
+            return Ok(());
+        }
+
+        #[cfg(test)]
+        {
+            self.cascades += 1;
         }
 
         let last_tier_index = self.tier_index(self.len() - 1);
 
-        let mut tier = &mut self.tiers[tier_index];
-        let mut prev_popped = Some(tier.pop_back());
-        tier.insert(index, elem);
+        let tier = self.tier_at_mut(tier_index);
+        let mut prev_popped = tier.pop_back();
+        tier.insert(tier_rank, elem);
+
+        // carry the overflow through every full tier until one has room for it
+        for i in tier_index + 1..=last_tier_index {
+            #[cfg(test)]
+            {
+                self.moves += 1;
+            }
 
-        for i in tier_index + 1..last_tier_index {
-            tier = &mut self.tiers[i];
+            let tier = self.tier_at_mut(i);
 
-            let prev_elem = prev_popped.take().expect("loop should always pop a value");
-            prev_popped = Some(tier.pop_push_front(prev_elem));
-        }
+            if !tier.is_full() {
+                tier.push_front(prev_popped);
+                self.len += 1;
 
-        tier = &mut self.tiers[last_tier_index];
+                return Ok(());
+            }
 
-        if tier.is_full() {
-            let prev_elem = prev_popped.take().expect("loop should always pop a value");
-            prev_popped = Some(tier.pop_push_front(prev_elem));
+            prev_popped = tier.pop_push_front(prev_popped);
+        }
 
-            tier = &mut self.tiers[last_tier_index + 1];
+        #[cfg(test)]
+        {
+            self.moves += 1;
         }
 
-        tier.push_front(prev_popped.take().expect("loop should always pop a value"));
+        self.tier_at_mut(last_tier_index + 1)
+            .push_front(prev_popped);
         self.len += 1;
+
+        Ok(())
+    }
+
+    // whole tiers of the batch are spliced in as fresh tiers, so only the remainder short of a
+    // tier cascades; every tier it passes is full, so it never moves more elements than sit
+    // behind `index`, which also keeps it ahead of rebuilding the vector around the batch
+    pub fn insert_all_at<I: IntoIterator<Item = T>>(&mut self, index: usize, items: I) {
+        assert!(index <= self.len());
+
+        let items: Vec<T> = items.into_iter().collect();
+        if items.is_empty() {
+            return;
+        }
+
+        let num_items = items.len();
+        assert!(
+            self.capacity_bound
+                .is_none_or(|bound| self.len() + num_items <= bound),
+            "vector is fixed at a capacity of {} elements",
+            self.capacity_bound.unwrap_or_default()
+        );
+        if self.len() + self.front_gap + num_items > self.capacity() {
+            self.close_front_gap();
+            self.reserve(num_items);
+        }
+
+        // lay the ring out from the first tier so fresh tiers can be spliced in by position
+        self.tiers.rotate_left(self.tier_head);
+        self.tier_head = 0;
+
+        let tier_capacity = self.tier_capacity();
+        let populated_tiers = self.populated_tiers();
+        let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+
+        let room = if tier_index == 0 {
+            tier_capacity - self.front_gap
+        } else {
+            tier_capacity
+        };
+        let num_displaced = self.tiers[tier_index].len() - tier_rank;
+        let fits = (room - tier_rank).min(num_items + num_displaced);
+        let num_fresh_tiers = (num_items + num_displaced - fits) / tier_capacity;
+
+        let tier = &mut self.tiers[tier_index];
+        let mut displaced = Vec::with_capacity(num_displaced);
+        while tier.len() > tier_rank {
+            displaced.push(tier.pop_back());
+        }
+
+        #[cfg(test)]
+        {
+            self.moves += num_displaced;
+        }
+
+        // the empty tiers past the back become the fresh tiers
+        let spliced = tier_index + 1..populated_tiers.max(tier_index + 1) + num_fresh_tiers;
+        self.tiers[spliced].rotate_right(num_fresh_tiers);
+
+        let mut batch = items.into_iter().chain(displaced.into_iter().rev());
+        for i in 0..=num_fresh_tiers {
+            let take = if i == 0 { fits } else { tier_capacity };
+            let tier = &mut self.tiers[tier_index + i];
+
+            for elem in batch.by_ref().take(take) {
+                tier.push_back(elem);
+            }
+        }
+        let mut carry: Vec<T> = batch.collect();
+
+        #[cfg(test)]
+        if !carry.is_empty() {
+            self.cascades += 1;
+        }
+
+        // every later tier takes the remainder on its front and hands as many on from its back
+        let mut overflow = Vec::with_capacity(carry.len());
+        for tier in self.tiers[tier_index + num_fresh_tiers + 1..].iter_mut() {
+            if carry.is_empty() {
+                break;
+            }
+
+            #[cfg(test)]
+            {
+                self.moves += carry.len();
+            }
+
+            if tier.is_full() {
+                tier.pop_push_front_run(&mut carry);
+                continue;
+            }
+
+            for elem in carry.drain(..).rev() {
+                if tier.is_full() {
+                    overflow.push(tier.pop_push_front(elem));
+                } else {
+                    tier.push_front(elem);
+                }
+            }
+
+            overflow.reverse();
+            mem::swap(&mut carry, &mut overflow);
+        }
+
+        debug_assert!(carry.is_empty());
+        self.len += num_items;
+    }
+
+    pub fn insert_iter<I: IntoIterator<Item = T>>(&mut self, index: usize, items: I) {
+        self.insert_all_at(index, items);
+    }
+
+    pub fn insert_slice(&mut self, index: usize, items: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_all_at(index, items.iter().cloned());
     }
 
     pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.len());
+        self.try_remove(index)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_remove(&mut self, index: usize) -> Result<T, TieredVectorError<T>> {
+        if index >= self.len() {
+            return Err(TieredVectorError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+                elem: None,
+            });
+        }
 
         let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+        let last_tier_index = self.populated_tiers() - 1;
+
+        if tier_index == last_tier_index {
+            let elem = self.tier_at_mut(tier_index).remove(tier_rank);
 
-        if !self.tiers[tier_index].is_full() {
             self.len -= 1;
-            return self.tiers[tier_index].remove(index);
+            if self.is_empty() {
+                self.reset_offsets();
+            }
+
+            return Ok(elem);
         }
 
-        let last_tier_index = self.tier_index(self.len() - 1);
-        let mut prev_popped = Some(self.tiers[last_tier_index].pop_front());
+        let mut prev_popped = Some(self.tier_at_mut(last_tier_index).pop_front());
 
         for i in (tier_index + 1..last_tier_index).rev() {
-            let tier = &mut self.tiers[i];
+            let tier = self.tier_at_mut(i);
 
             let prev_elem = prev_popped.take().expect("loop should always pop a value");
             prev_popped = Some(tier.pop_push_back(prev_elem));
         }
 
-        let tier = &mut self.tiers[tier_index];
-        let elem = tier.remove(index);
+        let tier = self.tier_at_mut(tier_index);
+        let elem = tier.remove(tier_rank);
         tier.push_back(prev_popped.take().expect("loop should always pop a value"));
 
         self.len -= 1;
-        return elem;
+        Ok(elem)
     }
 
     pub fn push(&mut self, elem: T) {
-        if self.is_full() {
-            self.expand();
+        if let Err(err) = self.try_push(elem) {
+            panic!("{}", err);
+        }
+    }
+
+    pub fn try_push(&mut self, elem: T) -> Result<(), TieredVectorError<T>> {
+        if self.at_capacity_bound() {
+            return Err(TieredVectorError::CapacityBound {
+                bound: self.len(),
+                elem,
+            });
         }
 
+        self.make_room_at_back();
+
         let index = self.tier_index(self.len());
-        let tier = &mut self.tiers[index];
+        let tier = self.tier_at_mut(index);
         assert!(!tier.is_full());
 
         tier.push_back(elem);
         self.len += 1;
+
+        Ok(())
     }
 
-    pub fn pop(&mut self) -> T {
-        assert!(!self.is_empty());
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
 
-        let index = self.tier_index(self.len() - 1);
-        let tier = &mut self.tiers[index];
-        assert!(!tier.is_empty());
+    // drops from the back so a panicking destructor never leaves a dropped element counted
+    pub fn truncate(&mut self, len: usize) {
+        while self.len() > len {
+            let tier_index = self.tier_index(self.len() - 1);
+            let keep = len.saturating_sub(self.tier_start(tier_index));
 
-        let elem = tier.pop_back();
+            while self.tier_at(tier_index).len() > keep {
+                let elem = self.tier_at_mut(tier_index).pop_back();
+                self.len -= 1;
+                drop(elem);
+            }
 
-        self.len -= 1;
-        return elem;
+            if keep == 0 {
+                self.tier_at_mut(tier_index).clear_and_leak();
+            }
+        }
+
+        if self.is_empty() {
+            self.reset_offsets();
+        }
     }
-}
 
-impl<T> Index<usize> for LinkedTieredVec<T> {
-    type Output = T;
+    // whole tiers are handed over when `at` falls on a tier boundary
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.len(),
+            "split index {} out of range for length {}",
+            at,
+            self.len()
+        );
 
-    fn index(&self, index: usize) -> &Self::Output {
-        assert!(index < self.len());
-        &self.tiers[self.tier_index(index)][index]
-    }
-}
+        // nothing is handed over, so the split-off vector need not allocate
+        if at == self.len() {
+            return Self::new();
+        }
 
-impl<T> IndexMut<usize> for LinkedTieredVec<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        assert!(index < self.len());
+        let mut other = Self::with_tier_capacity(self.tier_capacity());
 
-        let tier_index = self.tier_index(index);
-        &mut self.tiers[tier_index][index]
-    }
-}
+        // only tiers after the first start at their own rank 0, so those are the ones handed over
+        if at > 0 && self.tier_rank(at) == 0 {
+            let first_tier = self.tier_index(at);
+            for i in first_tier..self.populated_tiers() {
+                let physical = self.physical_tier(i);
+                mem::swap(&mut self.tiers[physical], &mut other.tiers[i - first_tier]);
+            }
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+            other.len = self.len() - at;
+            self.len = at;
+        } else {
+            let mut moved = Vec::with_capacity(self.len() - at);
+            while self.len() > at {
+                moved.push(self.pop());
+            }
 
-    #[test]
-    #[should_panic]
-    fn error_on_non_power_of_two_size() {
-        let _t: LinkedTieredVec<usize> = LinkedTieredVec::new(5);
-    }
+            other.extend(moved.into_iter().rev());
+        }
 
-    #[test]
-    #[should_panic]
-    fn error_on_small_size() {
-        let _t: LinkedTieredVec<usize> = LinkedTieredVec::new(1);
+        other
     }
 
-    #[test]
-    fn no_error_on_correct_size() {
-        let size = 4;
-        let t: LinkedTieredVec<usize> = LinkedTieredVec::new(size);
-        assert_eq!(t.len(), 0);
-        assert_eq!(t.capacity(), size * size);
-        assert_eq!(t.tier_capacity(), size);
-        assert!(t.is_empty());
-        assert!(!t.is_full());
+    pub fn pop(&mut self) -> T {
+        self.try_pop().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_pop(&mut self) -> Result<T, TieredVectorError<T>> {
+        if self.is_empty() {
+            return Err(TieredVectorError::Empty);
+        }
+
+        let index = self.tier_index(self.len() - 1);
+        let tier = self.tier_at_mut(index);
+        assert!(!tier.is_empty());
+
+        let elem = tier.pop_back();
+
+        self.len -= 1;
+        if self.is_empty() {
+            self.reset_offsets();
+        }
+
+        Ok(elem)
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        assert!(
+            !self.at_capacity_bound(),
+            "vector is fixed at a capacity of {} elements",
+            self.len()
+        );
+
+        if self.is_empty() {
+            self.push(elem);
+            return;
+        }
+
+        if self.front_gap > 0 {
+            self.tier_at_mut(0).push_front(elem);
+            self.front_gap -= 1;
+            self.len += 1;
+
+            return;
+        }
+
+        // with every tier in use there is no free tier to claim, so fall back to a cascade
+        if self.populated_tiers() == self.num_tiers() {
+            self.insert(0, elem);
+            return;
+        }
+
+        self.tier_head = self.physical_tier(self.num_tiers() - 1);
+        self.front_gap = self.tier_capacity() - 1;
+
+        self.tier_at_mut(0).push_front(elem);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> T {
+        assert!(!self.is_empty());
+
+        let spans_tiers = self.tier_index(self.len() - 1) > 0;
+        let elem = self.tier_at_mut(0).pop_front();
+        self.len -= 1;
+
+        if self.is_empty() {
+            self.reset_offsets();
+        } else if spans_tiers {
+            if self.tier_at(0).is_empty() {
+                self.tier_head = self.physical_tier(1);
+                self.front_gap = 0;
+            } else {
+                self.front_gap += 1;
+            }
+        }
+
+        elem
+    }
+
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.len(),
+            "rotation by {} out of range for length {}",
+            mid,
+            self.len()
+        );
+
+        // move whichever side is shorter across the ends
+        let k = self.len() - mid;
+        if mid == 0 || k == 0 {
+            return;
+        }
+
+        if mid <= k {
+            self.rotate_front_to_back(mid);
+        } else {
+            self.rotate_back_to_front(k);
+        }
+    }
+
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(
+            k <= self.len(),
+            "rotation by {} out of range for length {}",
+            k,
+            self.len()
+        );
+
+        self.rotate_left(self.len() - k);
+    }
+
+    // a whole tier moves by swapping it into the free tier past the back, or by only moving the
+    // head once every tier is in use; a partial last tier is then rebalanced against it with at
+    // most half a tier of moves, so only the runs short of a tier go element by element
+    fn rotate_front_to_back(&mut self, mut count: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        // the partial first tier goes over as one run, which leaves the front on a tier boundary
+        let front_len = self.tier_at(0).len();
+        if self.front_gap > 0 && count >= front_len {
+            self.move_front_to_back(front_len);
+            count -= front_len;
+        }
+
+        while count >= tier_capacity {
+            let populated_tiers = self.populated_tiers();
+            let back_len = self.tier_at(populated_tiers - 1).len();
+            if populated_tiers < self.num_tiers() {
+                self.swap_physical_tiers(
+                    self.physical_tier(0),
+                    self.physical_tier(populated_tiers),
+                );
+            }
+
+            self.tier_head = self.physical_tier(1);
+            count -= tier_capacity;
+
+            if back_len < tier_capacity {
+                self.rebalance_back(populated_tiers - 2, back_len);
+            }
+        }
+
+        self.move_front_to_back(count);
+    }
+
+    fn rotate_back_to_front(&mut self, mut count: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        // the partial last tier goes over as one run, which leaves the back on a tier boundary
+        let back_len = self.tier_at(self.populated_tiers() - 1).len();
+        if back_len < tier_capacity && count >= back_len {
+            self.move_back_to_front(back_len);
+            count -= back_len;
+        }
+
+        while count >= tier_capacity {
+            let front_len = self.tier_at(0).len();
+            let last_tier = self.physical_tier(self.populated_tiers() - 1);
+            let before_head = self.physical_tier(self.num_tiers() - 1);
+            if last_tier != before_head {
+                self.swap_physical_tiers(last_tier, before_head);
+            }
+
+            self.tier_head = before_head;
+            count -= tier_capacity;
+
+            if front_len < tier_capacity {
+                self.rebalance_front(front_len);
+            }
+        }
+
+        self.move_back_to_front(count);
+    }
+
+    // `count` is no more than the first tier holds
+    fn move_front_to_back(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let spans_tiers = self.tier_index(self.len() - 1) > 0;
+        let mut run = Vec::with_capacity(count);
+        for _ in 0..count {
+            run.push(self.tier_at_mut(0).pop_front());
+        }
+        self.len -= count;
+
+        if spans_tiers {
+            if self.tier_at(0).is_empty() {
+                self.tier_head = self.physical_tier(1);
+                self.front_gap = 0;
+            } else {
+                self.front_gap += count;
+            }
+        }
+
+        // the run stays in the vector, so pushing it back must not grow it
+        if self.len() + self.front_gap + count > self.capacity() {
+            self.close_front_gap();
+        }
+
+        for elem in run {
+            self.push(elem);
+        }
+    }
+
+    // `count` is no more than the last tier holds
+    fn move_back_to_front(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let last_tier = self.populated_tiers() - 1;
+        let mut run = Vec::with_capacity(count);
+        for _ in 0..count {
+            run.push(self.tier_at_mut(last_tier).pop_back());
+        }
+        self.len -= count;
+
+        for elem in run {
+            self.push_front(elem);
+        }
+    }
+
+    // the tier at `tier_index` holds `tier_len` elements ahead of a full tier, so either it is
+    // topped up from that tier, or its short run trades places with the full tier's tail and
+    // the two tiers swap
+    fn rebalance_back(&mut self, tier_index: usize, tier_len: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        if tier_capacity - tier_len <= tier_len {
+            for _ in tier_len..tier_capacity {
+                let elem = self.tier_at_mut(tier_index + 1).pop_front();
+                self.tier_at_mut(tier_index).push_back(elem);
+            }
+        } else {
+            for _ in 0..tier_len {
+                let elem = self.tier_at_mut(tier_index).pop_back();
+                let elem = self.tier_at_mut(tier_index + 1).pop_push_front(elem);
+                self.tier_at_mut(tier_index).push_front(elem);
+            }
+
+            self.swap_physical_tiers(
+                self.physical_tier(tier_index),
+                self.physical_tier(tier_index + 1),
+            );
+        }
+    }
+
+    // the mirror of `rebalance_back`, for a full first tier ahead of one holding the `tier_len`
+    // elements the front gap leaves room for
+    fn rebalance_front(&mut self, tier_len: usize) {
+        let tier_capacity = self.tier_capacity();
+
+        if tier_capacity - tier_len <= tier_len {
+            for _ in tier_len..tier_capacity {
+                let elem = self.tier_at_mut(0).pop_back();
+                self.tier_at_mut(1).push_front(elem);
+            }
+        } else {
+            for _ in 0..tier_len {
+                let elem = self.tier_at_mut(1).pop_front();
+                let elem = self.tier_at_mut(0).pop_push_back(elem);
+                self.tier_at_mut(1).push_back(elem);
+            }
+
+            self.swap_physical_tiers(self.physical_tier(0), self.physical_tier(1));
+        }
+    }
+
+    fn swap_physical_tiers(&mut self, a: usize, b: usize) {
+        self.tiers.swap(a, b);
+    }
+
+    pub fn into_vec_deque(mut self) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(self.len());
+
+        for tier in self.tiers_in_order_mut() {
+            while !tier.is_empty() {
+                deque.push_back(tier.pop_front());
+            }
+        }
+
+        self.len = 0;
+        deque
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let tier_capacity = self.tier_capacity();
+
+        // a vector that has not allocated yet must also be empty
+        if tier_capacity == 0 && self.is_empty() && self.tier_head == 0 && self.front_gap == 0 {
+            return Ok(());
+        }
+
+        if tier_capacity < 2 || !tier_capacity.is_power_of_two() {
+            return Err(format!(
+                "tier capacity {} is not a power of two of at least 2",
+                tier_capacity
+            ));
+        }
+
+        if self.front_gap >= tier_capacity || self.tier_head >= self.num_tiers() {
+            return Err(format!(
+                "ring offsets (head {}, gap {}) out of range for tier capacity {}",
+                self.tier_head, self.front_gap, tier_capacity
+            ));
+        }
+
+        let mut num_elements = 0;
+        let mut seen_partial_tier = false;
+
+        for (i, tier) in self.tiers_in_order().enumerate() {
+            if tier.capacity() != tier_capacity {
+                return Err(format!(
+                    "tier {} has capacity {} but expected {}",
+                    i,
+                    tier.capacity(),
+                    tier_capacity
+                ));
+            }
+
+            // the first tier gives up the slots in its front gap
+            let room = if i == 0 {
+                tier.capacity() - self.front_gap
+            } else {
+                tier.capacity()
+            };
+
+            if tier.len() > room {
+                return Err(format!(
+                    "tier {} tracks {} elements but can only hold {}",
+                    i,
+                    tier.len(),
+                    room
+                ));
+            }
+
+            if seen_partial_tier && !tier.is_empty() {
+                return Err(format!(
+                    "tier {} holds elements after a tier that is not full",
+                    i
+                ));
+            }
+
+            seen_partial_tier |= tier.len() < room;
+            num_elements += tier.len();
+        }
+
+        if num_elements != self.len() {
+            return Err(format!(
+                "len is {} but tiers hold {} elements",
+                self.len(),
+                num_elements
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn merge_into(mut self, dst: &mut LinkedTieredVec<T>) {
+        dst.reserve(self.len());
+
+        for tier in self.tiers_in_order_mut() {
+            while !tier.is_empty() {
+                dst.push(tier.pop_front());
+            }
+        }
+
+        self.len = 0;
+    }
+
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let old_len = self.len();
+        self.normalize();
+
+        // lay every tier out linearly and forget its elements for the duration of the
+        // extraction, so leaking the iterator can at worst leak elements but never double-drop
+        for tier in self.tiers.iter_mut() {
+            tier.rotate_reset();
+            tier.clear_and_leak();
+        }
+        self.len = 0;
+
+        ExtractIf {
+            vec: self,
+            pred,
+            idx: 0,
+            del: 0,
+            old_len,
+        }
+    }
+
+    // only valid while the vector is normalized and tiers are rotated to start at slot 0
+    fn slot_ptr(&mut self, rank: usize) -> *mut T {
+        let tier_index = self.tier_index(rank);
+        let masked_idx = self.mask(rank);
+
+        self.tiers[tier_index].slot_ptr(masked_idx)
+    }
+
+    // goes through the tier's slot pointer so references to other slots stay valid
+    fn elem_ptr(&mut self, rank: usize) -> *mut T {
+        let tier_index = self.tier_index(rank);
+        let tier_rank = self.tier_rank(rank);
+
+        let tier = self.tier_at_mut(tier_index);
+        let masked_idx = tier.masked_rank(tier_rank);
+
+        tier.slot_ptr(masked_idx)
+    }
+
+    pub fn compact_tier(&mut self, tier: usize) {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        self.tier_at_mut(tier).rotate_reset();
+    }
+
+    pub fn replace_tier_capacity(&mut self, new_tier_capacity: usize) {
+        assert!(new_tier_capacity.is_power_of_two());
+        assert!(new_tier_capacity.ge(&2));
+        assert!(
+            new_tier_capacity
+                .checked_pow(2)
+                .is_none_or(|cap| cap >= self.len()),
+            "tier capacity {} cannot hold {} elements",
+            new_tier_capacity,
+            self.len()
+        );
+
+        assert!(
+            self.capacity_bound.is_none_or(|bound| new_tier_capacity
+                .checked_pow(2)
+                .is_none_or(|cap| cap >= bound)),
+            "tier capacity {} cannot hold the fixed capacity",
+            new_tier_capacity
+        );
+
+        let mut rebuilt = Self::with_tier_capacity(new_tier_capacity);
+        rebuilt.capacity_bound = self.capacity_bound;
+        for tier in self.tiers_in_order_mut() {
+            while !tier.is_empty() {
+                rebuilt.push(tier.pop_front());
+            }
+        }
+
+        #[cfg(test)]
+        {
+            rebuilt.cascades = self.cascades;
+            rebuilt.expansions = self.expansions;
+            rebuilt.moves = self.moves;
+        }
+
+        self.len = 0;
+        *self = rebuilt;
+    }
+
+    // capacity is always the square of the tier capacity, so balance is measured against the
+    // layout `with_capacity` would choose for the current length
+    pub fn is_balanced(&self) -> bool {
+        let ideal_tier_capacity =
+            Self::tier_capacity_for(self.len().max(4)).expect("len always has a valid layout");
+
+        self.tier_capacity() <= ideal_tier_capacity * 2
+    }
+
+    pub fn get_or_push_default(&mut self, index: usize) -> &mut T
+    where
+        T: Default,
+    {
+        assert!(
+            index <= self.len(),
+            "index {} is past the end of a vector of len {}",
+            index,
+            self.len()
+        );
+
+        if index == self.len() {
+            self.push(T::default());
+        }
+
+        &mut self[index]
+    }
+
+    pub fn clone_range(&self, range: Range<usize>) -> LinkedTieredVec<T>
+    where
+        T: Clone,
+    {
+        assert!(range.start <= range.end);
+        assert!(range.end <= self.len());
+
+        let mut cloned = Self::with_capacity(range.len().max(4));
+        for i in range {
+            cloned.push(self[i].clone());
+        }
+
+        cloned
+    }
+
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.position_by(|candidate, best| candidate > best)
+    }
+
+    pub fn argmin(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.position_by(|candidate, best| candidate < best)
+    }
+
+    // strict comparison keeps the first of several equal candidates
+    fn position_by<F: Fn(&T, &T) -> bool>(&self, replaces: F) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut best = 0;
+        for i in 1..self.len() {
+            if replaces(&self[i], &self[best]) {
+                best = i;
+            }
+        }
+
+        Some(best)
+    }
+
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if needle.len() > self.len() {
+            return None;
+        }
+
+        (0..=self.len() - needle.len()).find(|&start| {
+            needle
+                .iter()
+                .enumerate()
+                .all(|(offset, elem)| self[start + offset] == *elem)
+        })
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.len() && b < self.len(),
+            "swap indices {} and {} out of range for length {}",
+            a,
+            b,
+            self.len()
+        );
+
+        let (tier_a, tier_b) = (self.tier_index(a), self.tier_index(b));
+        if tier_a == tier_b {
+            let (rank_a, rank_b) = (self.tier_rank(a), self.tier_rank(b));
+            self.tier_at_mut(tier_a).swap(rank_a, rank_b);
+            return;
+        }
+
+        // the ring may wrap, so order the pair by where the tiers sit in `tiers`
+        let (physical_a, physical_b) = (self.physical_tier(tier_a), self.physical_tier(tier_b));
+        let (low, high) = if physical_a < physical_b {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let (low_tier, low_rank) = (physical_a.min(physical_b), self.tier_rank(low));
+        let (high_tier, high_rank) = (physical_a.max(physical_b), self.tier_rank(high));
+        let (front, back) = self.tiers.split_at_mut(high_tier);
+
+        mem::swap(&mut front[low_tier][low_rank], &mut back[0][high_rank]);
+    }
+
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize)
+    where
+        T: Copy,
+    {
+        let Range { start, end } = resolve_range(src, self.len());
+        let count = end - start;
+        assert!(
+            dest <= self.len() - count,
+            "destination {} out of range for {} ranks in a vector of len {}",
+            dest,
+            count,
+            self.len()
+        );
+
+        unsafe { self.move_ranks(start, dest, count) };
+    }
+
+    // relocates the block so its first element ends up at `dest`, shifting the ranks in between
+    pub fn move_range<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        let Range { start, end } = resolve_range(src, self.len());
+        let count = end - start;
+        assert!(
+            dest <= self.len() - count,
+            "destination {} out of range for {} ranks in a vector of len {}",
+            dest,
+            count,
+            self.len()
+        );
+
+        if count == 0 || dest == start {
+            return;
+        }
+
+        // the block is parked in scratch memory while the ranks in between shift over; the
+        // scratch never takes ownership, so no element is dropped twice
+        let mut scratch: Vec<T> = Vec::with_capacity(count);
+        unsafe {
+            self.copy_out(start, scratch.as_mut_ptr(), count);
+
+            if dest < start {
+                self.move_ranks(dest, dest + count, start - dest);
+            } else {
+                self.move_ranks(end, start, dest - start);
+            }
+
+            self.copy_in(scratch.as_ptr(), dest, count);
+        }
+    }
+
+    pub fn swap_ranges<A, B>(&mut self, a: A, b: B)
+    where
+        A: RangeBounds<usize>,
+        B: RangeBounds<usize>,
+    {
+        let a = resolve_range(a, self.len());
+        let b = resolve_range(b, self.len());
+        assert_eq!(a.len(), b.len(), "swapped ranges must be the same length");
+        assert!(
+            a.end <= b.start || b.end <= a.start,
+            "swapped ranges must not overlap"
+        );
+
+        // the ranges are disjoint, so any pair of runs taken from them never overlaps either
+        let mut done = 0;
+        while done < a.len() {
+            let chunk = (a.len() - done)
+                .min(self.run_after(a.start + done))
+                .min(self.run_after(b.start + done));
+
+            unsafe {
+                ptr::swap_nonoverlapping(
+                    self.elem_ptr(a.start + done),
+                    self.elem_ptr(b.start + done),
+                    chunk,
+                );
+            }
+            done += chunk;
+        }
+    }
+
+    // how many ranks from `rank` onwards sit next to each other in memory
+    fn run_after(&self, rank: usize) -> usize {
+        let tier_index = self.tier_index(rank);
+        let slot = self.tier_at(tier_index).masked_rank(self.tier_rank(rank));
+        let tier_end = self.tier_start(tier_index + 1).min(self.len());
+
+        (tier_end - rank).min(self.tier_capacity() - slot)
+    }
+
+    // how many ranks ending just before `end` sit next to each other in memory
+    fn run_before(&self, end: usize) -> usize {
+        let last = end - 1;
+        let tier_index = self.tier_index(last);
+        let slot = self.tier_at(tier_index).masked_rank(self.tier_rank(last));
+
+        (end - self.tier_start(tier_index)).min(slot + 1)
+    }
+
+    // bitwise copies `count` ranks one contiguous run at a time, walking in whichever direction
+    // reads every source rank before it can be overwritten
+    unsafe fn move_ranks(&mut self, src: usize, dest: usize, count: usize) {
+        if dest <= src {
+            let mut done = 0;
+            while done < count {
+                let chunk = (count - done)
+                    .min(self.run_after(src + done))
+                    .min(self.run_after(dest + done));
+
+                ptr::copy(self.elem_ptr(src + done), self.elem_ptr(dest + done), chunk);
+                done += chunk;
+            }
+        } else {
+            let mut left = count;
+            while left > 0 {
+                let chunk = left
+                    .min(self.run_before(src + left))
+                    .min(self.run_before(dest + left));
+
+                left -= chunk;
+                ptr::copy(self.elem_ptr(src + left), self.elem_ptr(dest + left), chunk);
+            }
+        }
+    }
+
+    unsafe fn copy_out(&mut self, src: usize, dst: *mut T, count: usize) {
+        let mut done = 0;
+        while done < count {
+            let chunk = (count - done).min(self.run_after(src + done));
+
+            ptr::copy_nonoverlapping(self.elem_ptr(src + done), dst.add(done), chunk);
+            done += chunk;
+        }
+    }
+
+    unsafe fn copy_in(&mut self, src: *const T, dest: usize, count: usize) {
+        let mut done = 0;
+        while done < count {
+            let chunk = (count - done).min(self.run_after(dest + done));
+
+            ptr::copy_nonoverlapping(src.add(done), self.elem_ptr(dest + done), chunk);
+            done += chunk;
+        }
+    }
+
+    // backfills the hole with the last element, so nothing cascades
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len(),
+            "swap_remove index {} out of range for length {}",
+            index,
+            self.len()
+        );
+
+        let last = self.pop();
+        if index == self.len() {
+            return last;
+        }
+
+        mem::replace(&mut self[index], last)
+    }
+
+    pub fn swap_remove_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.swap_remove(0))
+    }
+
+    /// # Safety
+    ///
+    /// Only the slots from `head` to `tail` (wrapping, masked by the tier capacity) are
+    /// initialized; every other slot may hold uninitialized memory and must not be read.
+    pub unsafe fn tier_raw(&self, tier: usize) -> (usize, usize, &[MaybeUninit<T>]) {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        self.tier_at(tier).raw_parts()
+    }
+
+    // counts element moves against the current layout, leaving out the one-off cost of an
+    // expansion when the vector is already full
+    pub fn estimate_insert_cost(&self, index: usize) -> usize {
+        assert!(index <= self.len());
+
+        if index == self.len() {
+            return 0;
+        }
+
+        let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+        let tier = self.tier_at(tier_index);
+
+        if !tier.is_full() {
+            return if tier_rank == 0 {
+                0
+            } else {
+                tier.len() - tier_rank
+            };
+        }
+
+        // popping the back of the target tier frees the slot the shift moves into
+        let shift = if tier_rank == 0 {
+            0
+        } else {
+            tier.len() - 1 - tier_rank
+        };
+
+        let last_tier_index = self.tier_index(self.len() - 1);
+        let cascade_end = (tier_index + 1..=last_tier_index)
+            .find(|&i| !self.tier_at(i).is_full())
+            .unwrap_or(last_tier_index + 1);
+
+        1 + shift + (cascade_end - tier_index)
+    }
+
+    pub fn dedup_all(&mut self)
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut elements = Vec::with_capacity(self.len());
+        for tier in self.tiers_in_order_mut() {
+            while !tier.is_empty() {
+                elements.push(tier.pop_front());
+            }
+        }
+        self.len = 0;
+        self.reset_offsets();
+
+        let mut seen = HashSet::with_capacity(elements.len());
+        for elem in elements {
+            if seen.insert(elem.clone()) {
+                self.push(elem);
+            }
+        }
+    }
+
+    // rotates left by whole tiers, so the tier at `tier_shift` becomes the first one; only full
+    // tiers take part, which keeps the full-prefix invariant intact without moving elements
+    pub fn rotate_by_tier(&mut self, tier_shift: usize) {
+        // an unallocated vector has no tiers to rotate
+        if self.tier_capacity() == 0 {
+            return;
+        }
+
+        assert!(
+            self.len().is_multiple_of(self.tier_capacity()),
+            "rotating by tiers requires every populated tier to be full"
+        );
+        self.normalize();
+
+        let populated_tiers = self.len() / self.tier_capacity();
+        if populated_tiers == 0 {
+            return;
+        }
+
+        self.tiers[..populated_tiers].rotate_left(tier_shift % populated_tiers);
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(Ord::cmp);
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    // sorts in a scratch buffer and refills the same tiers, so the capacity is kept; a panicking
+    // comparator leaves the vector empty
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        let mut elements = Vec::with_capacity(self.len());
+        self.drain_all_into(&mut elements);
+
+        elements.sort_by(compare);
+        self.refill(elements);
+    }
+
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(Ord::cmp);
+    }
+
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        let mut elements = Vec::with_capacity(self.len());
+        self.drain_all_into(&mut elements);
+
+        elements.sort_unstable_by(compare);
+        self.refill(elements);
+    }
+
+    // edits are sorted by rank against the original vector, so one sweep rebuilds it
+    pub fn apply_edits<I: IntoIterator<Item = Edit<T>>>(&mut self, edits: I) {
+        let edits: Vec<Edit<T>> = edits.into_iter().collect();
+        let len = self.len();
+
+        // validate the whole script up front so a bad one leaves the vector untouched
+        let mut consumed = 0;
+        let mut new_len = len;
+        for edit in edits.iter() {
+            let rank = edit.rank();
+            assert!(rank >= consumed, "edit at rank {} is out of order", rank);
+
+            let in_range = match edit {
+                Edit::Insert(..) => rank <= len,
+                Edit::Remove(_) | Edit::Replace(..) => rank < len,
+            };
+            assert!(
+                in_range,
+                "edit at rank {} out of range for a vector of len {}",
+                rank, len
+            );
+
+            match edit {
+                Edit::Insert(..) => new_len += 1,
+                Edit::Remove(_) => {
+                    consumed = rank + 1;
+                    new_len -= 1;
+                }
+                Edit::Replace(..) => consumed = rank + 1,
+            }
+        }
+
+        assert!(
+            self.capacity_bound.is_none_or(|bound| new_len <= bound),
+            "vector is fixed at a capacity of {} elements",
+            self.capacity_bound.unwrap_or_default()
+        );
+
+        let mut old = Vec::with_capacity(len);
+        self.drain_all_into(&mut old);
+
+        let mut old = old.into_iter();
+        let mut next = 0;
+        let mut elements = Vec::with_capacity(new_len);
+        for edit in edits {
+            let rank = edit.rank();
+            elements.extend(old.by_ref().take(rank - next));
+            next = rank;
+
+            match edit {
+                Edit::Insert(_, elem) => elements.push(elem),
+                Edit::Remove(_) => {
+                    old.next();
+                    next += 1;
+                }
+                Edit::Replace(_, elem) => {
+                    old.next();
+                    elements.push(elem);
+                    next += 1;
+                }
+            }
+        }
+        elements.extend(old);
+
+        self.grow_once_for(new_len);
+        self.refill(elements);
+    }
+
+    fn refill(&mut self, elements: Vec<T>) {
+        debug_assert!(self.is_empty() && elements.len() <= self.capacity());
+
+        for elem in elements {
+            self.push(elem);
+        }
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    // a write cursor trails the read cursor and each kept element is swapped down to it, so the
+    // duplicates collect at the back and a single truncate drops them and fixes up the tiers
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        if self.len() <= 1 {
+            return;
+        }
+
+        let mut write = 0;
+        for read in 1..self.len() {
+            let (curr, prev) = (self.elem_ptr(read), self.elem_ptr(write));
+
+            // `read` is always ahead of `write`, so the two never alias
+            if !same_bucket(unsafe { &mut *curr }, unsafe { &mut *prev }) {
+                write += 1;
+                if read != write {
+                    self.swap(read, write);
+                }
+            }
+        }
+
+        self.truncate(write + 1);
+    }
+
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut sorted: Vec<T> = self.into_vec_deque().into();
+        sorted.sort();
+
+        sorted
+    }
+
+    // non-empty tiers always form a prefix of the ring, so the last rank tells where it ends
+    pub fn populated_tiers(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.tier_index(self.len() - 1) + 1
+        }
+    }
+
+    // pairs of (tier len, number of tiers at that len), ordered by len
+    pub fn tier_load_histogram(&self) -> Vec<(usize, usize)> {
+        let mut histogram = BTreeMap::new();
+        for i in 0..self.num_tiers() {
+            *histogram.entry(self.tiers[i].len()).or_insert(0) += 1;
+        }
+
+        histogram.into_iter().collect()
+    }
+
+    // expects the vector to already be sorted
+    pub fn insert_sorted_unique(&mut self, elem: T) -> bool
+    where
+        T: Ord,
+    {
+        match self.binary_search(&elem) {
+            Ok(_) => false,
+            Err(rank) => {
+                self.insert(rank, elem);
+                true
+            }
+        }
+    }
+
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|elem| elem.cmp(x))
+    }
+
+    pub fn binary_search_by_key<B, F>(&self, key: &B, mut f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.binary_search_by(|elem| f(elem).cmp(key))
+    }
+
+    // every tier but the last is full, so the tiers are searched by their first element and
+    // only the one tier that can hold the match is searched rank by rank
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (mut low, mut high) = (0, self.populated_tiers());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if f(&self.tier_at(mid)[0]) == Ordering::Greater {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        let Some(tier_index) = low.checked_sub(1) else {
+            return Err(0);
+        };
+
+        let tier = self.tier_at(tier_index);
+        let tier_start = self.tier_start(tier_index);
+
+        let (mut low, mut high) = (0, tier.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match f(&tier[mid]) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(tier_start + mid),
+            }
+        }
+
+        Err(tier_start + low)
+    }
+
+    // same two-level search as `binary_search_by`, for a predicate that holds on a prefix
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (mut low, mut high) = (0, self.populated_tiers());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if pred(&self.tier_at(mid)[0]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        let Some(tier_index) = low.checked_sub(1) else {
+            return 0;
+        };
+
+        let tier = self.tier_at(tier_index);
+        let (mut low, mut high) = (1, tier.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if pred(&tier[mid]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        self.tier_start(tier_index) + low
+    }
+
+    // first rank whose element is not less than `x`
+    pub fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|elem| elem < x)
+    }
+
+    // first rank whose element is greater than `x`
+    pub fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|elem| elem <= x)
+    }
+
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.iter().is_sorted()
+    }
+
+    pub fn remove_indices(&mut self, indices: &[usize]) -> Vec<T> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let Some(&first) = indices.first() else {
+            return Vec::new();
+        };
+        assert!(indices[indices.len() - 1] < self.len());
+
+        // everything from the first removal onwards is detached once and pushed back compacted
+        let mut detached = Vec::with_capacity(self.len() - first);
+        while self.len() > first {
+            detached.push(self.pop());
+        }
+
+        let mut removed = Vec::with_capacity(indices.len());
+        let mut to_remove = indices.into_iter().peekable();
+
+        for (rank, elem) in (first..).zip(detached.into_iter().rev()) {
+            if to_remove.next_if_eq(&rank).is_some() {
+                removed.push(elem);
+            } else {
+                self.push(elem);
+            }
+        }
+
+        removed
+    }
+
+    pub fn drain_all_into(&mut self, out: &mut Vec<T>) {
+        out.clear();
+        out.reserve(self.len());
+
+        for tier in self.tiers_in_order_mut() {
+            tier.drain_into(out);
+        }
+
+        self.len = 0;
+        self.reset_offsets();
+    }
+
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut elements = Vec::new();
+        self.drain_all_into(&mut elements);
+
+        elements
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut elements = Vec::with_capacity(self.len());
+        elements.extend(self.iter().cloned());
+
+        elements
+    }
+
+    pub fn compare_and_swap(&mut self, index: usize, expected: &T, new: T) -> Result<T, &T>
+    where
+        T: PartialEq,
+    {
+        if self[index] != *expected {
+            return Err(&self[index]);
+        }
+
+        Ok(std::mem::replace(&mut self[index], new))
+    }
+
+    pub fn slot_state(&self, tier: usize, buffer_index: usize) -> SlotState {
+        assert!(
+            tier < self.num_tiers(),
+            "tier index {} out of range for {} tiers",
+            tier,
+            self.num_tiers()
+        );
+
+        self.tier_at(tier).slot_state(buffer_index)
+    }
+
+    pub fn update_range<R, F>(&mut self, range: R, mut f: F)
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(usize, &mut T),
+    {
+        let Range { mut start, end } = resolve_range(range, self.len());
+
+        // resolve each tier once and walk its ranks directly
+        while start < end {
+            let tier_index = self.tier_index(start);
+            let tier_rank = self.tier_rank(start);
+            let count = (self.tier_start(tier_index + 1) - start).min(end - start);
+            let tier = self.tier_at_mut(tier_index);
+
+            for offset in 0..count {
+                f(start + offset, &mut tier[tier_rank + offset]);
+            }
+
+            start += count;
+        }
+    }
+
+    pub fn remove_reporting(&mut self, index: usize) -> Option<(T, usize)> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let elem = self.remove(index);
+        Some((elem, self.len()))
+    }
+
+    // grows straight to the final layout, rather than doubling once per expansion
+    fn grow_once_for(&mut self, additional: usize) {
+        let required_capacity = self
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
+
+        if self.capacity() < required_capacity {
+            let tier_capacity =
+                Self::tier_capacity_for(required_capacity.max(4)).expect("capacity overflow");
+            self.replace_tier_capacity(tier_capacity);
+
+            #[cfg(test)]
+            {
+                self.expansions += 1;
+            }
+        } else if self.capacity() - self.front_gap < required_capacity {
+            self.normalize();
+        }
+    }
+
+    pub fn reserve_and_fill<F: FnMut(usize) -> T>(&mut self, additional: usize, mut f: F) {
+        self.grow_once_for(additional);
+
+        for _ in 0..additional {
+            let rank = self.len();
+            self.push(f(rank));
+        }
+    }
+
+    // fills whole tier tails with bulk copies instead of pushing element by element
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        if let Some(bound) = self.capacity_bound {
+            assert!(
+                self.len() + other.len() <= bound,
+                "vector is fixed at a capacity of {} elements",
+                bound
+            );
+        }
+        self.grow_once_for(other.len());
+
+        let mut remaining = other;
+        while !remaining.is_empty() {
+            let tier_index = self.tier_index(self.len());
+            let room = self.tier_start(tier_index + 1) - self.len();
+            let chunk = &remaining[..room.min(remaining.len())];
+            let copied = self.tier_at_mut(tier_index).extend_from_slice(chunk);
+
+            self.len += copied;
+            remaining = &remaining[copied..];
+        }
+    }
+
+    pub fn extend_exact<I: ExactSizeIterator<Item = T>>(&mut self, iter: I) {
+        self.grow_once_for(iter.len());
+
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+
+    // every slot of every tier in buffer order, so wrapped tiers show their gap explicitly
+    pub fn to_physical_vec(&self) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        let mut slots = Vec::with_capacity(self.capacity());
+        for i in 0..self.num_tiers() {
+            let tier = &self.tiers[i];
+            slots.extend((0..tier.capacity()).map(|slot| tier.get(slot).cloned()));
+        }
+
+        slots
+    }
+
+    // both inputs must already be sorted; ties take the element from `self` first
+    pub fn merge_sorted(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        let mut merged = Self::with_capacity((self.len() + other.len()).max(4));
+        let mut left = self.into_vec_deque();
+        let mut right = other.into_vec_deque();
+
+        while let (Some(l), Some(r)) = (left.front(), right.front()) {
+            let next = if r < l {
+                right.pop_front()
+            } else {
+                left.pop_front()
+            };
+            merged.push(next.expect("front was just observed"));
+        }
+
+        for elem in left.into_iter().chain(right) {
+            merged.push(elem);
+        }
+
+        merged
+    }
+
+    pub fn partition<F: FnMut(&T) -> bool>(self, mut pred: F) -> (Self, Self) {
+        let mut matching = Self::with_tier_capacity(2);
+        let mut rest = Self::with_tier_capacity(2);
+
+        for elem in self.into_vec_deque() {
+            if pred(&elem) {
+                matching.push(elem);
+            } else {
+                rest.push(elem);
+            }
+        }
+
+        (matching, rest)
+    }
+
+    pub fn clone_minimal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.clone_range(0..self.len())
+    }
+
+    pub fn eq_by<F: FnMut(&T, &T) -> bool>(&self, other: &Self, mut cmp: F) -> bool {
+        self.len() == other.len() && (0..self.len()).all(|i| cmp(&self[i], &other[i]))
+    }
+
+    fn tier_span(&self, tier_index: usize) -> TierSpan {
+        TierSpan {
+            index: tier_index,
+            physical: self.physical_tier(tier_index),
+            start: self.tier_start(tier_index),
+            end: self.tier_start(tier_index + 1),
+        }
+    }
+
+    // spans holding the first and the last rank of a range
+    fn end_spans(&self, range: &Range<usize>) -> (TierSpan, TierSpan) {
+        // an empty range never reads its spans, and a vector that has not allocated has no tiers
+        if range.is_empty() {
+            let span = TierSpan {
+                index: 0,
+                physical: 0,
+                start: range.start,
+                end: range.start,
+            };
+            return (span, span);
+        }
+
+        let last = range.end.max(range.start + 1) - 1;
+        (
+            self.tier_span(self.tier_index(range.start)),
+            self.tier_span(self.tier_index(last)),
+        )
+    }
+
+    fn span_elem(&self, span: TierSpan, rank: usize) -> &T {
+        &self.tiers[span.physical][rank - span.start]
+    }
+
+    // goes through the slot pointer so references to other slots stay valid
+    fn span_ptr(&mut self, span: TierSpan, rank: usize) -> *mut T {
+        let tier = &mut self.tiers[span.physical];
+        let masked_idx = tier.masked_rank(rank - span.start);
+
+        tier.slot_ptr(masked_idx)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self, 0..self.len())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let len = self.len();
+        IterMut::new(self, 0..len)
+    }
+
+    // the contiguous runs backing the elements in rank order, two for a tier that wraps
+    pub fn segments(&self) -> impl Iterator<Item = &[T]> {
+        self.tiers_in_order()
+            .take(self.populated_tiers())
+            .flat_map(|tier| {
+                let (first, second) = tier.as_slices();
+                [first, second]
+            })
+            .filter(|segment| !segment.is_empty())
+    }
+
+    pub fn segments_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let populated_tiers = self.populated_tiers();
+        self.tiers_in_order_mut()
+            .take(populated_tiers)
+            .flat_map(|tier| {
+                let (first, second) = tier.as_mut_slices();
+                [first, second]
+            })
+            .filter(|segment| !segment.is_empty())
+    }
+
+    // starts at the tier holding the first rank instead of walking up from rank 0
+    pub fn iter_range<R: RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+        Iter::new(self, resolve_range(range, self.len()))
+    }
+
+    pub fn iter_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+        let range = resolve_range(range, self.len());
+        IterMut::new(self, range)
+    }
+
+    // Index can only hand out references, so borrowed windows come from these instead
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> TieredSlice<'_, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSlice {
+            vec: self,
+            start,
+            end,
+        }
+    }
+
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> TieredSliceMut<'_, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSliceMut {
+            vec: self,
+            start,
+            end,
+        }
+    }
+
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        Chunks {
+            vec: self,
+            start: 0,
+            end: self.len(),
+            size: chunk_size,
+        }
+    }
+
+    pub fn windows(&self, window_size: usize) -> Windows<'_, T> {
+        assert!(window_size > 0, "window size must be non-zero");
+        Windows {
+            vec: self,
+            start: 0,
+            size: window_size,
+        }
+    }
+
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.tiers_in_order_mut()
+            .flat_map(Tier::iter_mut)
+            .enumerate()
+    }
+
+    pub fn rank_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        (0..self.len()).find(|&i| self[i] == *value)
+    }
+}
+
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut LinkedTieredVec<T>,
+    pred: F,
+    idx: usize,
+    del: usize,
+    old_len: usize,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.idx < self.old_len {
+            let curr = self.vec.slot_ptr(self.idx);
+            let matched = (self.pred)(unsafe { &mut *curr });
+            self.idx += 1;
+
+            if matched {
+                self.del += 1;
+                return Some(unsafe { ptr::read(curr) });
+            }
+
+            if self.del > 0 {
+                let dst = self.vec.slot_ptr(self.idx - 1 - self.del);
+                unsafe { ptr::copy_nonoverlapping(curr, dst, 1) };
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<T, F> Drop for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // close the gap left by extracted elements over the part that was never visited
+        if self.del > 0 {
+            for i in self.idx..self.old_len {
+                let src = self.vec.slot_ptr(i);
+                let dst = self.vec.slot_ptr(i - self.del);
+
+                unsafe { ptr::copy_nonoverlapping(src, dst, 1) };
+            }
+        }
+
+        let new_len = self.old_len - self.del;
+        let tier_capacity = self.vec.tier_capacity();
+
+        for (i, tier) in self.vec.tiers.iter_mut().enumerate() {
+            let tier_len = new_len.saturating_sub(i * tier_capacity).min(tier_capacity);
+            tier.tail_forward_by(tier_len);
+        }
+
+        self.vec.len = new_len;
+    }
+}
+
+pub struct Chunks<'a, T> {
+    vec: &'a LinkedTieredVec<T>,
+    start: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = TieredSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let end = self.end.min(self.start + self.size);
+        let chunk = self.vec.slice(self.start..end);
+        self.start = end;
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.start).div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+// windows are views over the vector itself, so one straddling a tier boundary needs no buffer
+pub struct Windows<'a, T> {
+    vec: &'a LinkedTieredVec<T>,
+    start: usize,
+    size: usize,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = TieredSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start + self.size > self.vec.len() {
+            return None;
+        }
+
+        let window = self.vec.slice(self.start..self.start + self.size);
+        self.start += 1;
+
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.vec.len() + 1).saturating_sub(self.start + self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+// resolves `range` against a sequence of `len` elements, panicking like slice indexing does
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "range starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end {} out of range for a vector of len {}",
+        end,
+        len
+    );
+
+    start..end
+}
+
+// a window of ranks over a borrowed vector, indexed from the start of the window
+pub struct TieredSlice<'a, T> {
+    vec: &'a LinkedTieredVec<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> TieredSlice<'a, T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.vec.get(self.start + index)
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::new(self.vec, self.start..self.end)
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> TieredSlice<'a, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSlice {
+            vec: self.vec,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl<T> Clone for TieredSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TieredSlice<'_, T> {}
+
+impl<T> Index<usize> for TieredSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len());
+        &self.vec[self.start + index]
+    }
+}
+
+impl<'a, T> IntoIterator for TieredSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct TieredSliceMut<'a, T> {
+    vec: &'a mut LinkedTieredVec<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> TieredSliceMut<'a, T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.vec.get(self.start + index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.vec.get_mut(self.start + index)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.vec, self.start..self.end)
+    }
+
+    pub fn as_slice(&self) -> TieredSlice<'_, T> {
+        TieredSlice {
+            vec: self.vec,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> TieredSlice<'_, T> {
+        self.as_slice().slice(range)
+    }
+
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> TieredSliceMut<'_, T> {
+        let Range { start, end } = resolve_range(range, self.len());
+        TieredSliceMut {
+            vec: self.vec,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl<T> Index<usize> for TieredSliceMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len());
+        &self.vec[self.start + index]
+    }
+}
+
+impl<T> IndexMut<usize> for TieredSliceMut<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.len());
+        &mut self.vec[self.start + index]
+    }
+}
+
+impl<'a, T> IntoIterator for TieredSliceMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new(self.vec, self.start..self.end)
+    }
+}
+
+pub struct Iter<'a, T> {
+    vec: &'a LinkedTieredVec<T>,
+    front: usize,
+    back: usize,
+    front_span: TierSpan,
+    back_span: TierSpan,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn new(vec: &'a LinkedTieredVec<T>, range: Range<usize>) -> Self {
+        let (front_span, back_span) = vec.end_spans(&range);
+        Self {
+            vec,
+            front: range.start,
+            back: range.end,
+            front_span,
+            back_span,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        if self.front == self.front_span.end {
+            self.front_span = self.vec.tier_span(self.front_span.index + 1);
+        }
+
+        let elem = self.vec.span_elem(self.front_span, self.front);
+        self.front += 1;
+
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        if self.back < self.back_span.start {
+            self.back_span = self.vec.tier_span(self.back_span.index - 1);
+        }
+
+        Some(self.vec.span_elem(self.back_span, self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec,
+            front: self.front,
+            back: self.back,
+            front_span: self.front_span,
+            back_span: self.back_span,
+        }
+    }
+}
+
+// every rank is yielded at most once, so the handed out references never alias
+pub struct IterMut<'a, T> {
+    vec: &'a mut LinkedTieredVec<T>,
+    front: usize,
+    back: usize,
+    front_span: TierSpan,
+    back_span: TierSpan,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    fn new(vec: &'a mut LinkedTieredVec<T>, range: Range<usize>) -> Self {
+        let (front_span, back_span) = vec.end_spans(&range);
+        Self {
+            vec,
+            front: range.start,
+            back: range.end,
+            front_span,
+            back_span,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        if self.front == self.front_span.end {
+            self.front_span = self.vec.tier_span(self.front_span.index + 1);
+        }
+
+        let elem = self.vec.span_ptr(self.front_span, self.front);
+        self.front += 1;
+
+        Some(unsafe { &mut *elem })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        if self.back < self.back_span.start {
+            self.back_span = self.vec.tier_span(self.back_span.index - 1);
+        }
+
+        let elem = self.vec.span_ptr(self.back_span, self.back);
+        Some(unsafe { &mut *elem })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+// the ranks one tier holds, so walking a range only resolves a tier when crossing into it
+#[derive(Clone, Copy)]
+struct TierSpan {
+    index: usize,
+    physical: usize,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> IntoIterator for &'a LinkedTieredVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedTieredVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for LinkedTieredVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let back_tier = self.populated_tiers();
+        IntoIter {
+            vec: self,
+            front_tier: 0,
+            back_tier,
+        }
+    }
+}
+
+// pops straight out of the tiers, leaving emptied tiers behind at both ends
+pub struct IntoIter<T> {
+    vec: LinkedTieredVec<T>,
+    front_tier: usize,
+    back_tier: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.vec.is_empty() {
+            return None;
+        }
+
+        while self.vec.tier_at(self.front_tier).is_empty() {
+            self.front_tier += 1;
+        }
+
+        self.vec.len -= 1;
+        Some(self.vec.tier_at_mut(self.front_tier).pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.vec.len(), Some(self.vec.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.vec.is_empty() {
+            return None;
+        }
+
+        while self.vec.tier_at(self.back_tier - 1).is_empty() {
+            self.back_tier -= 1;
+        }
+
+        self.vec.len -= 1;
+        Some(self.vec.tier_at_mut(self.back_tier - 1).pop_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+pub fn zip_elements<'a, T, U>(
+    a: &'a LinkedTieredVec<T>,
+    b: &'a LinkedTieredVec<U>,
+) -> impl Iterator<Item = (&'a T, &'a U)> {
+    (0..a.len().min(b.len())).map(move |i| (&a[i], &b[i]))
+}
+
+impl<T> Default for LinkedTieredVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// as with `get`, an indexed `&T` stays valid until the next `&mut self` call
+impl<T> Index<usize> for LinkedTieredVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len());
+        &self.tier_at(self.tier_index(index))[self.tier_rank(index)]
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedTieredVec<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.len());
+
+        let tier_index = self.tier_index(index);
+        let tier_rank = self.tier_rank(index);
+        &mut self.tier_at_mut(tier_index)[tier_rank]
+    }
+}
+
+// the alternate form also lists how full each tier is, in logical order
+impl<T: Debug> Debug for LinkedTieredVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return f.debug_list().entries(self.iter()).finish();
+        }
+
+        let tier_lens: Vec<usize> = (0..self.num_tiers())
+            .map(|i| self.tier_at(i).len())
+            .collect();
+        f.debug_struct("LinkedTieredVec")
+            .field("len", &self.len())
+            .field("tier_capacity", &self.tier_capacity())
+            .field("tier_lens", &tier_lens)
+            .field("elements", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<LinkedTieredVec<U>> for LinkedTieredVec<T> {
+    fn eq(&self, other: &LinkedTieredVec<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedTieredVec<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for LinkedTieredVec<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for LinkedTieredVec<T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for LinkedTieredVec<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for LinkedTieredVec<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T> FromIterator<T> for LinkedTieredVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+
+        let mut tiered_vec = Self::with_capacity(lower_bound.max(4));
+        for elem in iter {
+            tiered_vec.push(elem);
+        }
+
+        tiered_vec
+    }
+}
+
+impl<T> Extend<T> for LinkedTieredVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+
+        // a bounded vector must never grow past its bound, so leave it to push to reject
+        if self.capacity_bound.is_none() {
+            self.grow_once_for(lower_bound);
+        }
+
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+// sized once up front, so every element lands with a plain push and nothing cascades
+impl<T> From<Vec<T>> for LinkedTieredVec<T> {
+    fn from(elements: Vec<T>) -> Self {
+        let mut tiered_vec = Self::with_capacity(elements.len().max(4));
+        tiered_vec.extend_exact(elements.into_iter());
+
+        tiered_vec
+    }
+}
+
+// serialized as a plain sequence, so the tier geometry is rebuilt to fit the element count
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for LinkedTieredVec<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for LinkedTieredVec<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedTieredVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[should_panic]
+    fn error_on_non_power_of_two_size() {
+        let _t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_on_small_size() {
+        let _t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(1);
+    }
+
+    #[test]
+    fn no_error_on_correct_size() {
+        let size = 4;
+        let t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(size);
+        assert_eq!(t.len(), 0);
+        assert_eq!(t.capacity(), size * size);
+        assert_eq!(t.tier_capacity(), size);
+        assert!(t.is_empty());
+        assert!(!t.is_full());
+    }
+
+    #[test]
+    fn with_minimum_capacity() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_capacity(4);
+        assert_eq!(4, t.capacity());
+        assert_eq!(2, t.tier_capacity());
+
+        t = LinkedTieredVec::with_capacity(8);
+        assert_eq!(16, t.capacity());
+        assert_eq!(4, t.tier_capacity());
+
+        t = LinkedTieredVec::with_capacity(128);
+        assert_eq!(256, t.capacity());
+        assert_eq!(16, t.tier_capacity());
+    }
+
+    #[test]
+    fn insert() {
+        let size = 4;
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(size);
+        assert_eq!(t.tier_capacity(), size);
+
+        for i in 0..size {
+            t.insert(i, i * 2);
+            assert_eq!(t.len(), i + 1);
+        }
+
+        for i in 0..size {
+            let result = t.get(i);
+            assert!(result.is_some());
+            assert_eq!(*result.unwrap(), i * 2);
+        }
+
+        assert_eq!(t.len(), size);
+        assert!(!t.is_empty());
+        assert!(!t.is_full());
+    }
+
+    #[test]
+    fn expand() {
+        let size = 4;
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(size);
+
+        for i in 0..size * size {
+            t.insert(i, i);
+            assert_eq!(t[i], i);
+        }
+        assert_eq!(t.tier_capacity(), size);
+        assert_eq!(t.len(), size * size);
+        assert!(t.is_full());
+
+        t.insert(size * size, size * size);
+        assert_eq!(t.tier_capacity(), size * 2);
+        assert_eq!(t.len(), (size * size) + 1);
+        assert!(!t.is_full());
+
+        for i in 0..((size * size) + 1) {
+            let result = t.get(i);
+            assert!(result.is_some());
+            assert_eq!(*result.unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn insert_at_end_without_cascade() {
+        let mut pushed: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        let mut inserted: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+
+        for i in 0..100 {
+            pushed.push(i);
+            inserted.insert(inserted.len(), i);
+        }
+
+        assert_eq!(inserted.len(), pushed.len());
+        assert_eq!(inserted.capacity(), pushed.capacity());
+        for i in 0..pushed.len() {
+            assert_eq!(inserted[i], pushed[i]);
+        }
+
+        assert_eq!(inserted.cascades, 0);
+    }
+
+    #[test]
+    fn insert_into_full_last_tier() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..8 {
+            t.push(i);
+        }
+
+        t.insert(5, 100);
+        assert_eq!(t.cascades, 1);
+
+        let expected = [0, 1, 2, 3, 4, 100, 5, 6, 7];
+        assert_eq!(t.len(), expected.len());
+        for (i, elem) in expected.iter().enumerate() {
+            assert_eq!(t[i], *elem);
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let size = 16;
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(size);
+        assert_eq!(t.capacity(), size * size);
+
+        for i in 0..size * size / 8 {
+            t.insert(i, i);
+            assert_eq!(t[i], i);
+        }
+        assert_eq!(t.tier_capacity(), size);
+        assert_eq!(t.len(), size * size / 8);
+        assert_eq!(t.capacity(), size * size);
+
+        t.remove(0);
+
+        assert_eq!(*t.get(0).unwrap(), 1);
+        assert_eq!(t.len(), (size * size / 8) - 1);
+
+        t.remove(0);
+        assert_eq!(*t.get(0).unwrap(), 2);
+        assert_eq!(t.len(), (size * size / 8) - 2);
+    }
+
+    #[test]
+    fn contract() {}
+
+    #[test]
+    fn vec_deque_round_trip() {
+        let t: LinkedTieredVec<usize> = (0..100).collect();
+        assert_eq!(t.len(), 100);
+
+        let deque = t.into_vec_deque();
+        assert_eq!(deque.len(), 100);
+        assert!(deque.iter().copied().eq(0..100));
+
+        let t: LinkedTieredVec<usize> = deque.into_iter().collect();
+        assert_eq!(t.len(), 100);
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn vec_deque_moves_without_double_drop() {
+        let counter = std::rc::Rc::new(());
+
+        let t: LinkedTieredVec<_> = (0..40).map(|_| counter.clone()).collect();
+        assert_eq!(std::rc::Rc::strong_count(&counter), 41);
+
+        let deque = t.into_vec_deque();
+        assert_eq!(std::rc::Rc::strong_count(&counter), 41);
+
+        drop(deque);
+        assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn reserve_tiers() {
+        let mut t: LinkedTieredVec<usize> = (0..16).collect();
+        assert_eq!(t.tier_capacity(), 4);
+
+        t.reserve_tiers(4);
+        assert!(t.tiers.capacity() >= 8);
+        assert_eq!(t.tier_capacity(), 4);
+        assert_eq!(t.len(), 16);
+
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+
+        let tiers_ptr = t.tiers.as_ptr();
+        t.push(16);
+        assert_eq!(t.tier_capacity(), 8);
+        assert_eq!(t.tiers.as_ptr(), tiers_ptr);
+
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let mut t: LinkedTieredVec<usize> = (0..40).collect();
+        assert_eq!(t.validate(), Ok(()));
+
+        t.len = 41;
+        assert_eq!(
+            t.validate(),
+            Err("len is 41 but tiers hold 40 elements".to_string())
+        );
+
+        t.tiers[0].pop_back();
+        t.len = 39;
+        assert_eq!(
+            t.validate(),
+            Err("tier 1 holds elements after a tier that is not full".to_string())
+        );
+
+        t.tiers[0].push_back(7);
+        t.len = 40;
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[test]
+    fn from_iter_exact() {
+        let v: Vec<usize> = (0..100).collect();
+        let t = LinkedTieredVec::from_iter_exact(v.into_iter());
+
+        assert_eq!(t.len(), 100);
+        assert_eq!(t.capacity(), 256);
+        assert_eq!(t.tier_capacity(), 16);
+        assert_eq!(t.expansions, 0);
+
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+
+        let t = LinkedTieredVec::from_iter_exact(vec![0; 64].into_iter());
+        assert_eq!(t.capacity(), 64);
+        assert_eq!(t.expansions, 0);
+    }
+
+    #[test]
+    fn capacity_after_expand() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(t.capacity_after_expand(), 64);
+
+        for i in 0..t.capacity() {
+            t.push(i);
+        }
+        let predicted = t.capacity_after_expand();
+
+        t.push(16);
+        assert_eq!(t.capacity(), predicted);
+        assert_eq!(t.capacity_after_expand(), 256);
+    }
+
+    #[test]
+    fn tier_capacity_for_large_inputs() {
+        assert_eq!(
+            LinkedTieredVec::<usize>::tier_capacity_for(1 << 62),
+            Some(1 << 31)
+        );
+        assert_eq!(
+            LinkedTieredVec::<usize>::tier_capacity_for((1 << 61) + 1),
+            Some(1 << 31)
+        );
+        assert_eq!(LinkedTieredVec::<usize>::tier_capacity_for(1 << 63), None);
+        assert_eq!(
+            LinkedTieredVec::<usize>::tier_capacity_for((1 << 62) + 1),
+            None
+        );
+        assert_eq!(
+            LinkedTieredVec::<usize>::tier_capacity_for(usize::MAX),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn with_capacity_overflow() {
+        let _t: LinkedTieredVec<usize> = LinkedTieredVec::with_capacity(usize::MAX);
+    }
+
+    #[test]
+    fn reserve() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+        assert_eq!(t.capacity(), 16);
+
+        t.reserve(6);
+        assert_eq!(t.capacity(), 16);
+
+        t.reserve(100);
+        assert_eq!(t.capacity(), 256);
+        assert_eq!(t.expansions, 2);
+
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn merge_into() {
+        let mut persistent: LinkedTieredVec<usize> = (0..10).collect();
+
+        for round in 0..3 {
+            let temporary: LinkedTieredVec<usize> =
+                (0..20).map(|i| 100 * (round + 1) + i).collect();
+            temporary.merge_into(&mut persistent);
+        }
+
+        assert_eq!(persistent.len(), 70);
+        assert_eq!(persistent.validate(), Ok(()));
+
+        for i in 0..10 {
+            assert_eq!(persistent[i], i);
+        }
+        for round in 0..3 {
+            for i in 0..20 {
+                assert_eq!(persistent[10 + round * 20 + i], 100 * (round + 1) + i);
+            }
+        }
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+
+        let evens: Vec<usize> = t.extract_if(|elem| *elem % 2 == 0).collect();
+        assert_eq!(evens, vec![0, 2, 4, 6, 8]);
+
+        assert_eq!(t.len(), 5);
+        assert_eq!(t.validate(), Ok(()));
+        for i in 0..t.len() {
+            assert_eq!(t[i], i * 2 + 1);
+        }
+    }
+
+    #[test]
+    fn extract_if_partially_consumed() {
+        let mut t: LinkedTieredVec<usize> = (0..100).collect();
+
+        {
+            let mut extracted = t.extract_if(|elem| *elem % 3 == 0);
+            assert_eq!(extracted.next(), Some(0));
+            assert_eq!(extracted.next(), Some(3));
+        }
+
+        assert_eq!(t.len(), 98);
+        assert_eq!(t.validate(), Ok(()));
+
+        let expected: Vec<usize> = (0..100).filter(|i| *i != 0 && *i != 3).collect();
+        for (i, elem) in expected.iter().enumerate() {
+            assert_eq!(t[i], *elem);
+        }
+    }
+
+    #[test]
+    fn matches_vec_under_mixed_operations() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(256);
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        let mut v: Vec<usize> = Vec::new();
+
+        for i in 0..2_000 {
+            if v.is_empty() || rng.gen_ratio(2, 3) {
+                let index = rng.gen_range(0..=v.len());
+                t.insert(index, i);
+                v.insert(index, i);
+            } else {
+                let index = rng.gen_range(0..v.len());
+                assert_eq!(t.remove(index), v.remove(index));
+            }
+
+            assert_eq!(t.len(), v.len());
+        }
+
+        assert_eq!(t.validate(), Ok(()));
+        for (i, elem) in v.iter().enumerate() {
+            assert_eq!(t[i], *elem);
+        }
+    }
+
+    #[test]
+    fn compact_tier() {
+        let mut t: LinkedTieredVec<usize> = (0..16).collect();
+        for i in 0..3 {
+            t.insert(0, 100 + i);
+        }
+        assert_ne!(t.tiers[0].masked_head(), 0);
+
+        let other_heads: Vec<usize> = (1..t.num_tiers())
+            .map(|i| t.tiers[i].masked_head())
+            .collect();
+
+        t.compact_tier(0);
+        assert_eq!(t.tiers[0].masked_head(), 0);
+        assert_eq!(
+            (1..t.num_tiers())
+                .map(|i| t.tiers[i].masked_head())
+                .collect::<Vec<_>>(),
+            other_heads
+        );
+
+        let expected = [102, 101, 100].into_iter().chain(0..16);
+        for (i, elem) in expected.enumerate() {
+            assert_eq!(t[i], elem);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "tier index 4 out of range for 4 tiers")]
+    fn compact_tier_out_of_range() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        t.compact_tier(4);
+    }
+
+    #[test]
+    fn insert_all_at() {
+        let mut bulk: LinkedTieredVec<usize> = (0..1000).collect();
+        let mut single = bulk.clone();
+
+        bulk.insert_all_at(500, 2000..2020);
+        for (i, elem) in (2000..2020).enumerate() {
+            single.insert(500 + i, elem);
+        }
+
+        assert_eq!(bulk.len(), 1020);
+        assert!(bulk.validate().is_ok());
+        for i in 0..single.len() {
+            assert_eq!(bulk[i], single[i]);
+        }
+
+        // a batch short of a tier cascades like inserting one at a time, on top of the twelve
+        // elements behind the insertion point in its tier; the last eight single inserts land
+        // in the next tier and pass one tier fewer
+        assert_eq!(single.moves, 12 * 16 + 8 * 15);
+        assert_eq!(bulk.moves, 12 + 20 * 16);
+
+        // three whole tiers of the batch are spliced in, and only the eight left over cascade
+        // through the fourteen full tiers behind them
+        let mut spliced: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(64);
+        spliced.extend(0..1000);
+        spliced.insert_all_at(100, 5000..5200);
+
+        assert_eq!(spliced.moves, 28 + 8 * 14);
+        assert!(spliced.validate().is_ok());
+        assert!(spliced
+            .iter()
+            .copied()
+            .eq((0..100).chain(5000..5200).chain(100..1000)));
+
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        t.push(0);
+        t.push(1);
+        t.push(2);
+        t.insert_all_at(1, 10..20);
+        t.insert_all_at(t.len(), 20..22);
+        t.insert_all_at(0, Vec::new());
+
+        assert!(t.validate().is_ok());
+        let expected: Vec<usize> = [0]
+            .into_iter()
+            .chain(10..20)
+            .chain([1, 2, 20, 21])
+            .collect();
+        assert_eq!(t.len(), expected.len());
+        for (i, elem) in expected.into_iter().enumerate() {
+            assert_eq!(t[i], elem);
+        }
+    }
+
+    #[test]
+    fn insert_all_at_matches_vec_after_ring_moves() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(2483);
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        let mut v: Vec<usize> = Vec::new();
+
+        for round in 0..300 {
+            // push_front and pop_front leave the ring turned and the first tier short
+            if rng.gen_bool(0.5) {
+                t.push_front(round);
+                v.insert(0, round);
+            } else if !v.is_empty() {
+                assert_eq!(t.pop_front(), v.remove(0));
+            }
+
+            let index = rng.gen_range(0..=v.len());
+            let batch = round * 100..round * 100 + rng.gen_range(0..40);
+            t.insert_all_at(index, batch.clone());
+            v.splice(index..index, batch);
+
+            assert_eq!(t.validate(), Ok(()));
+            assert!(t.iter().eq(v.iter()));
+
+            if v.len() > 2_000 {
+                t.truncate(100);
+                v.truncate(100);
+            }
+        }
+    }
+
+    #[test]
+    fn replace_tier_capacity() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        for i in 0..16 {
+            t.push(i);
+        }
+        assert_eq!(t.tier_capacity(), 4);
+
+        t.replace_tier_capacity(8);
+        assert_eq!(t.tier_capacity(), 8);
+        assert_eq!(t.len(), 16);
+        assert!(t.validate().is_ok());
+        for i in 0..16 {
+            assert_eq!(t[i], i);
+        }
+
+        t.replace_tier_capacity(4);
+        assert_eq!(t.tier_capacity(), 4);
+        assert!(t.validate().is_ok());
+        for i in 0..16 {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "tier capacity 2 cannot hold 16 elements")]
+    fn replace_tier_capacity_too_small() {
+        let mut t: LinkedTieredVec<usize> = (0..16).collect();
+        t.replace_tier_capacity(2);
+    }
+
+    #[test]
+    fn is_balanced() {
+        let mut t: LinkedTieredVec<usize> = (0..1000).collect();
+        assert!(t.is_balanced());
+
+        let mut grown: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        for i in 0..1000 {
+            grown.push(i);
+        }
+        assert!(grown.is_balanced());
+
+        t.replace_tier_capacity(128);
+        assert!(!t.is_balanced());
+
+        let mut skewed: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(64);
+        for i in 0..16 {
+            skewed.push(i);
+        }
+        assert!(!skewed.is_balanced());
+
+        skewed.replace_tier_capacity(4);
+        assert!(skewed.is_balanced());
+    }
+
+    #[test]
+    fn get_or_push_default() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        t.push(1);
+
+        *t.get_or_push_default(0) += 10;
+        assert_eq!(t[0], 11);
+        assert_eq!(t.len(), 1);
+
+        for i in 1..8 {
+            assert_eq!(*t.get_or_push_default(i), 0);
+            *t.get_or_push_default(i) = i;
+        }
+        assert_eq!(t.len(), 8);
+        for i in 1..8 {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is past the end of a vector of len 2")]
+    fn get_or_push_default_past_end() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        t.push(0);
+        t.push(1);
+        t.get_or_push_default(3);
+    }
+
+    #[test]
+    fn clone_range() {
+        let mut t: LinkedTieredVec<String> = (0..40).map(|i| i.to_string()).collect();
+
+        let mut middle = t.clone_range(10..30);
+        assert_eq!(middle.len(), 20);
+        assert_eq!(middle.tier_capacity(), 8);
+        for i in 0..20 {
+            assert_eq!(middle[i], (10 + i).to_string());
+        }
+
+        middle[0].push('!');
+        t[11].push('?');
+        assert_eq!(t[10], "10");
+        assert_eq!(middle[1], "11");
+
+        let empty = t.clone_range(5..5);
+        assert!(empty.is_empty());
+
+        let whole = t.clone_range(0..t.len());
+        assert_eq!(whole.len(), t.len());
+        for i in 0..t.len() {
+            assert_eq!(whole[i], t[i]);
+        }
+    }
+
+    #[test]
+    fn argmax_argmin() {
+        let t: LinkedTieredVec<i32> = [3, -2, 9, 4, 0, 7].into_iter().collect();
+        assert_eq!(t.argmax(), Some(2));
+        assert_eq!(t.argmin(), Some(1));
+
+        let ties: LinkedTieredVec<i32> = [1, 5, 0, 5, 0, 2].into_iter().collect();
+        assert_eq!(ties.argmax(), Some(1));
+        assert_eq!(ties.argmin(), Some(2));
+
+        let empty: LinkedTieredVec<i32> = LinkedTieredVec::with_tier_capacity(2);
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.argmin(), None);
+    }
+
+    #[test]
+    fn find_subslice() {
+        let t: LinkedTieredVec<usize> = (0..16).chain(0..16).collect();
+        assert_eq!(t.tier_capacity(), 8);
+
+        assert_eq!(t.find_subslice(&[6, 7, 8, 9]), Some(6));
+        assert_eq!(t.find_subslice(&[14, 15, 0, 1]), Some(14));
+        assert_eq!(t.find_subslice(&[3, 5]), None);
+        assert_eq!(t.find_subslice(&(0..33).collect::<Vec<_>>()), None);
+        assert_eq!(t.find_subslice(&[]), Some(0));
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        assert_eq!(empty.find_subslice(&[]), Some(0));
+        assert_eq!(empty.find_subslice(&[0]), None);
+    }
+
+    #[test]
+    fn swap_remove_front() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.swap_remove_front(), Some(0));
+        assert_eq!(t.len(), 9);
+        assert_eq!(t[0], 9);
+        for i in 1..9 {
+            assert_eq!(t[i], i);
+        }
+
+        let mut single: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        single.push(7);
+        assert_eq!(single.swap_remove_front(), Some(7));
+        assert!(single.is_empty());
+        assert_eq!(single.swap_remove_front(), None);
+    }
+
+    #[test]
+    fn tier_raw() {
+        let mut t: LinkedTieredVec<u32> = (0..16).collect();
+        t.insert(0, 100);
+        t.insert(0, 101);
+
+        let (head, tail, slots) = unsafe { t.tier_raw(0) };
+        let mask = slots.len() - 1;
+        assert_ne!(head & mask, 0);
+
+        let saved: Vec<std::mem::MaybeUninit<u32>> = slots.to_vec();
+        drop(t);
+
+        let restored: Vec<u32> = (0..tail.wrapping_sub(head))
+            .map(|rank| unsafe { saved[head.wrapping_add(rank) & mask].assume_init() })
+            .collect();
+        assert_eq!(restored, [101, 100, 0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "tier index 2 out of range for 2 tiers")]
+    fn tier_raw_out_of_range() {
+        let t: LinkedTieredVec<u32> = LinkedTieredVec::with_tier_capacity(2);
+        let _ = unsafe { t.tier_raw(2) };
+    }
+
+    #[test]
+    fn estimate_insert_cost() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(8);
+        for i in 0..32 {
+            t.push(i);
+        }
+
+        assert_eq!(t.estimate_insert_cost(t.len()), 0);
+
+        // one move out of tier 0, one per later full tier, one into the first empty tier
+        let front_cost = t.estimate_insert_cost(0);
+        assert_eq!(front_cost, 5);
+        assert!(front_cost <= t.num_tiers());
+
+        // the shift inside tier 1 dominates the cascade for a mid-tier insert
+        assert_eq!(t.estimate_insert_cost(10), 1 + 5 + 3);
+
+        t.push(32);
+        assert_eq!(t.estimate_insert_cost(33), 0);
+        assert_eq!(t.estimate_insert_cost(32), 0);
+        assert_eq!(t.estimate_insert_cost(0), 5);
+    }
+
+    #[test]
+    fn dedup_all() {
+        let mut t: LinkedTieredVec<usize> = [1, 2, 1, 3, 2, 4].into_iter().collect();
+        t.dedup_all();
+
+        assert_eq!(t.len(), 4);
+        assert!(t.validate().is_ok());
+        for (i, elem) in [1, 2, 3, 4].into_iter().enumerate() {
+            assert_eq!(t[i], elem);
+        }
+
+        let mut strings: LinkedTieredVec<String> = (0..100).map(|i| (i % 7).to_string()).collect();
+        strings.dedup_all();
+        assert_eq!(strings.len(), 7);
+        for i in 0..7 {
+            assert_eq!(strings[i], i.to_string());
+        }
+    }
+
+    #[test]
+    fn rotate_by_tier() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..12 {
+            t.push(i);
+        }
+
+        t.rotate_by_tier(1);
+        assert!(t.validate().is_ok());
+        for i in 0..12 {
+            assert_eq!(t[i], (i + 4) % 12);
+        }
+
+        t.rotate_by_tier(5);
+        for i in 0..12 {
+            assert_eq!(t[i], i);
+        }
+
+        t.push(12);
+        t.push(13);
+        t.push(14);
+        t.push(15);
+        t.rotate_by_tier(3);
+        for i in 0..16 {
+            assert_eq!(t[i], (i + 12) % 16);
+        }
+
+        let mut empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        empty.rotate_by_tier(2);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "rotating by tiers requires every populated tier to be full")]
+    fn rotate_by_tier_partial() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+        t.rotate_by_tier(1);
+    }
+
+    #[test]
+    fn into_sorted_vec() {
+        let t: LinkedTieredVec<String> = (0..50).map(|i| ((i * 37) % 50).to_string()).collect();
+
+        let mut expected: Vec<String> = (0..50).map(|i| i.to_string()).collect();
+        expected.sort();
+
+        let sorted = t.into_sorted_vec();
+        assert_eq!(sorted.len(), 50);
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn tier_load_histogram() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(8);
+        for i in 0..60 {
+            t.push(i);
+        }
+        for _ in 0..30 {
+            t.remove(3);
+        }
+
+        // 30 elements over tiers of 8: three full, one partial and four empty
+        assert_eq!(t.tier_load_histogram(), vec![(0, 4), (6, 1), (8, 3)]);
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.tier_load_histogram(), vec![(0, 4)]);
+    }
+
+    #[test]
+    fn contract_shrinks_tiers_vec() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(16);
+        for i in 0..20 {
+            t.push(i);
+        }
+
+        t.try_contract(t.len());
+        assert_eq!(t.tier_capacity(), 8);
+        assert!(t.tiers.capacity() < t.tiers.len() * 2);
+        assert!(t.tiers.iter().all(|tier| tier.capacity() == 8));
+        assert!(t.validate().is_ok());
+
+        for i in 0..20 {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn insert_sorted_unique() {
+        let mut t: LinkedTieredVec<usize> = (0..20).map(|i| i * 2).collect();
+
+        assert!(t.insert_sorted_unique(7));
+        assert_eq!(t.len(), 21);
+        assert_eq!(t[3], 6);
+        assert_eq!(t[4], 7);
+        assert_eq!(t[5], 8);
+
+        assert!(!t.insert_sorted_unique(8));
+        assert!(!t.insert_sorted_unique(7));
+        assert_eq!(t.len(), 21);
+
+        assert!(t.insert_sorted_unique(100));
+        assert!(t.insert_sorted_unique(1));
+        for i in 1..t.len() {
+            assert!(t[i - 1] < t[i]);
+        }
+
+        let mut empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        assert!(empty.insert_sorted_unique(5));
+        assert!(!empty.insert_sorted_unique(5));
+        assert_eq!(empty.len(), 1);
+    }
+
+    #[test]
+    fn remove_indices() {
+        let mut t: LinkedTieredVec<usize> = (0..6).map(|i| i * 10).collect();
+
+        assert_eq!(t.remove_indices(&[4, 1, 3, 1]), vec![10, 30, 40]);
+        assert_eq!(t.len(), 3);
+        assert!(t.validate().is_ok());
+        assert_eq!(t[0], 0);
+        assert_eq!(t[1], 20);
+        assert_eq!(t[2], 50);
+
+        assert!(t.remove_indices(&[]).is_empty());
+        assert_eq!(t.len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_indices_out_of_range() {
+        let mut t: LinkedTieredVec<usize> = (0..6).collect();
+        t.remove_indices(&[2, 6]);
+    }
+
+    #[test]
+    fn drain_all_into() {
+        let mut t: LinkedTieredVec<String> = (0..30).map(|i| i.to_string()).collect();
+        let capacity = t.capacity();
+        let mut out = vec![String::from("stale")];
+
+        t.drain_all_into(&mut out);
+        assert!(t.is_empty());
+        assert_eq!(t.capacity(), capacity);
+        assert_eq!(out, (0..30).map(|i| i.to_string()).collect::<Vec<_>>());
+
+        for i in 0..10 {
+            t.insert(0, i.to_string());
+        }
+
+        t.drain_all_into(&mut out);
+        assert!(t.is_empty());
+        assert!(t.validate().is_ok());
+        assert_eq!(
+            out,
+            (0..10).rev().map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn compare_and_swap() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.compare_and_swap(6, &6, 60), Ok(6));
+        assert_eq!(t[6], 60);
+
+        assert_eq!(t.compare_and_swap(6, &6, 600), Err(&60));
+        assert_eq!(t[6], 60);
+        assert_eq!(t.len(), 10);
+    }
+
+    #[test]
+    fn zip_elements() {
+        let a: LinkedTieredVec<usize> = (0..20).collect();
+        let b: LinkedTieredVec<String> = (0..20).map(|i| i.to_string()).collect();
+
+        let zipped: Vec<(&usize, &String)> = linked::zip_elements(&a, &b).collect();
+        assert_eq!(zipped.len(), 20);
+        for (x, s) in zipped {
+            assert_eq!(x.to_string(), *s);
+        }
+
+        let short: LinkedTieredVec<usize> = (0..5).collect();
+        assert_eq!(linked::zip_elements(&a, &short).count(), 5);
+    }
+
+    #[test]
+    fn try_grow() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.insert(0, i);
+        }
+        let capacity = t.capacity();
+
+        assert!(t.try_grow().is_ok());
+        assert_eq!(t.capacity(), capacity * 4);
+        assert_eq!(t.expansions, 1);
+        assert!(t.validate().is_ok());
+        for i in 0..10 {
+            assert_eq!(t[i], 9 - i);
+        }
+
+        assert!(t.try_grow().is_ok());
+        assert_eq!(t.capacity(), capacity * 16);
+        assert!(t.tiers.iter().all(|tier| tier.capacity() == 16));
+    }
+
+    #[test]
+    fn slot_state() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..6 {
+            t.push(i);
+        }
+        t.insert(0, 6);
+
+        // the cascade pushes 3 onto the front of tier 1, wrapping it to [4, 5, n, 3]
+        assert_eq!(t.slot_state(0, 3), SlotState::Occupied);
+        assert_eq!(t.slot_state(1, 1), SlotState::Occupied);
+        assert_eq!(t.slot_state(1, 2), SlotState::Free);
+        assert_eq!(t.slot_state(1, 3), SlotState::Occupied);
+        assert_eq!(t.slot_state(2, 0), SlotState::Free);
+        assert_eq!(t.slot_state(3, 4), SlotState::OutOfRange);
+    }
+
+    #[test]
+    fn tier_accessors_follow_the_ring() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        t.extend(1..9);
+        t.push_front(0);
+
+        // the pushed element claims the last physical tier, which is now logical tier 0
+        assert_eq!(t.tier_head, 3);
+        let (head, tail, slots) = unsafe { t.tier_raw(0) };
+        let mask = slots.len() - 1;
+        assert_eq!(tail.wrapping_sub(head), 1);
+        assert_eq!(unsafe { slots[head & mask].assume_init_read() }, 0);
+        assert_eq!(t.slot_state(0, head & mask), SlotState::Occupied);
+        assert_eq!(
+            t.slot_state(0, head.wrapping_add(1) & mask),
+            SlotState::Free
+        );
+
+        t.compact_tier(0);
+        let (head, _, _) = unsafe { t.tier_raw(0) };
+        assert_eq!(head & mask, 0);
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..9));
+    }
+
+    #[test]
+    fn update_range() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.insert(0, 9 - i);
+        }
+
+        let mut visited = Vec::new();
+        t.update_range(3..7, |rank, elem| {
+            visited.push(rank);
+            *elem *= 2;
+        });
+
+        assert_eq!(visited, [3, 4, 5, 6]);
+        for i in 0..10 {
+            let expected = if (3..7).contains(&i) { i * 2 } else { i };
+            assert_eq!(t[i], expected);
+        }
+
+        t.update_range(..=1, |_, elem| *elem += 100);
+        t.update_range(8.., |_, elem| *elem += 100);
+        t.update_range(5..5, |_, _| panic!("empty range should not visit"));
+        assert_eq!(t[0], 100);
+        assert_eq!(t[1], 101);
+        assert_eq!(t[2], 2);
+        assert_eq!(t[9], 109);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 11 out of range for a vector of len 10")]
+    fn update_range_out_of_bounds() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+        t.update_range(5..11, |_, _| {});
+    }
+
+    #[test]
+    fn remove_reporting() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.remove_reporting(4), Some((4, 9)));
+        assert_eq!(t.remove_reporting(8), Some((9, 8)));
+        assert_eq!(t[4], 5);
+
+        assert_eq!(t.remove_reporting(8), None);
+        assert_eq!(t.len(), 8);
+    }
+
+    #[test]
+    fn reserve_and_fill() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        t.push(0);
+
+        t.reserve_and_fill(10000, |rank| rank * 2);
+        assert_eq!(t.len(), 10001);
+        assert_eq!(t.expansions, 1);
+        assert_eq!(t.capacity(), 16384);
+        assert!(t.validate().is_ok());
+        for i in 0..t.len() {
+            assert_eq!(t[i], i * 2);
+        }
+
+        t.reserve_and_fill(100, |rank| rank);
+        assert_eq!(t.expansions, 1);
+        assert_eq!(t[10100], 10100);
+    }
+
+    #[test]
+    fn to_physical_vec() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..6 {
+            t.push(i);
+        }
+        t.insert(0, 6);
+        t.insert(0, 7);
+
+        // tier 0 wraps to head 2, tier 1 takes the two displaced elements on its front
+        assert_eq!(
+            t.to_physical_vec(),
+            [
+                Some(0),
+                Some(1),
+                Some(7),
+                Some(6),
+                Some(4),
+                Some(5),
+                Some(2),
+                Some(3),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_sorted() {
+        use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+        static COMPARISONS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Counted(usize);
+
+        impl PartialOrd for Counted {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Counted {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                COMPARISONS.fetch_add(1, AtomicOrdering::Relaxed);
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let a: LinkedTieredVec<Counted> = [1, 3, 5].into_iter().map(Counted).collect();
+        let b: LinkedTieredVec<Counted> = [2, 4, 6].into_iter().map(Counted).collect();
+
+        let merged = a.merge_sorted(b);
+        assert_eq!(merged.len(), 6);
+        for i in 0..6 {
+            assert_eq!(merged[i], Counted(i + 1));
+        }
+
+        // a linear merge compares at most once per element placed before one side runs out
+        assert!(COMPARISONS.load(AtomicOrdering::Relaxed) <= 5);
+
+        let evens: LinkedTieredVec<usize> = (0..100).map(|i| i * 2).collect();
+        let odds: LinkedTieredVec<usize> = (0..50).map(|i| i * 2 + 1).collect();
+        let merged = evens.merge_sorted(odds);
+        assert!(merged.validate().is_ok());
+        for i in 1..merged.len() {
+            assert!(merged[i - 1] <= merged[i]);
+        }
+        assert_eq!(merged[99], 99);
+        assert_eq!(merged[149], 198);
+    }
+
+    #[test]
+    fn partition() {
+        let t: LinkedTieredVec<usize> = (0..10).collect();
+        let (evens, odds) = t.partition(|elem| elem % 2 == 0);
+
+        assert_eq!(evens.len(), 5);
+        assert_eq!(odds.len(), 5);
+        for i in 0..5 {
+            assert_eq!(evens[i], i * 2);
+            assert_eq!(odds[i], i * 2 + 1);
+        }
+
+        let t: LinkedTieredVec<usize> = (0..10).collect();
+        let (all, none) = t.partition(|_| true);
+        assert_eq!(all.len(), 10);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn clone_minimal() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_capacity(4096);
+        for i in 0..10 {
+            t.push(i);
+        }
+        assert_eq!(t.capacity(), 4096);
+
+        let cloned = t.clone_minimal();
+        assert_eq!(cloned.capacity(), 16);
+        assert_eq!(cloned.tier_capacity(), 4);
+        assert_eq!(t.capacity(), 4096);
+        for i in 0..10 {
+            assert_eq!(cloned[i], i);
+        }
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(64);
+        assert_eq!(empty.clone_minimal().capacity(), 4);
+    }
+
+    #[test]
+    fn eq_by() {
+        let approx = |a: &f64, b: &f64| (a - b).abs() < 1e-6;
+
+        let a: LinkedTieredVec<f64> = (0..20).map(|i| i as f64 / 3.0).collect();
+        let close: LinkedTieredVec<f64> = (0..20).map(|i| i as f64 / 3.0 + 1e-9).collect();
+        let far: LinkedTieredVec<f64> = (0..20).map(|i| i as f64 / 3.0 + 1e-3).collect();
+        let short: LinkedTieredVec<f64> = (0..19).map(|i| i as f64 / 3.0).collect();
+
+        assert!(a.eq_by(&close, approx));
+        assert!(!a.eq_by(&far, approx));
+        assert!(!a.eq_by(&short, approx));
+    }
+
+    #[test]
+    fn fixed_capacity() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::fixed_capacity(10);
+        let capacity = t.capacity();
+
+        for i in 0..10 {
+            assert_eq!(t.try_insert(i / 2, i), Ok(()));
+        }
+        assert_eq!(t.len(), 10);
+
+        assert_eq!(
+            t.try_push(10),
+            Err(TieredVectorError::CapacityBound {
+                bound: 10,
+                elem: 10
+            })
+        );
+        assert_eq!(
+            t.try_insert(0, 11).map_err(|err| err.into_elem()),
+            Err(Some(11))
+        );
+        assert_eq!(t.len(), 10);
+
+        t.remove(0);
+        assert_eq!(t.try_push(12), Ok(()));
+        assert_eq!(t.try_push(13).map_err(|err| err.into_elem()), Err(Some(13)));
+
+        assert_eq!(t.capacity(), capacity);
+        assert_eq!(t.expansions, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "vector is fixed at a capacity of 6 elements")]
+    fn fixed_capacity_push_past_bound() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::fixed_capacity(6);
+        for i in 0..7 {
+            t.push(i);
+        }
+    }
+
+    #[test]
+    fn iter_mut_indexed() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.insert(0, i);
+        }
+
+        for (rank, elem) in t.iter_mut_indexed() {
+            *elem = rank;
+        }
+
+        assert_eq!(t.iter_mut_indexed().count(), 10);
+        for i in 0..10 {
+            assert_eq!(t.get(i), Some(&i));
+        }
+    }
+
+    #[test]
+    fn rank_of() {
+        let t: LinkedTieredVec<usize> = (0..20).chain([7, 30]).collect();
+
+        assert_eq!(t.rank_of(&18), Some(18));
+        assert_eq!(t.rank_of(&30), Some(21));
+        assert_eq!(t.rank_of(&7), Some(7));
+        assert_eq!(t.rank_of(&99), None);
+    }
+
+    #[test]
+    fn extend_exact() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        t.push(0);
+
+        t.extend_exact(1..10001);
+        assert_eq!(t.len(), 10001);
+        assert_eq!(t.capacity(), 16384);
+        assert_eq!(t.expansions, 1);
+        assert!(t.validate().is_ok());
+        for i in 0..t.len() {
+            assert_eq!(t[i], i);
+        }
+    }
+
+    #[test]
+    fn populated_tiers() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(t.populated_tiers(), 0);
+
+        for i in 0..40 {
+            t.insert(i / 2, i);
+            if i % 3 == 0 {
+                t.remove(i / 4);
+            }
+
+            let non_empty = t.tiers.iter().filter(|tier| !tier.is_empty()).count();
+            assert_eq!(t.populated_tiers(), non_empty);
+        }
+
+        while !t.is_empty() {
+            let populated = t.populated_tiers();
+            t.remove(0);
+
+            assert!(t.tiers[populated..].iter().all(|tier| tier.is_empty()));
+            assert!(t.validate().is_ok());
+        }
+        assert_eq!(t.populated_tiers(), 0);
+    }
+
+    #[test]
+    fn iter() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..20 {
+            t.insert(0, 19 - i);
+        }
+        t.remove(3);
+        t.insert(3, 3);
+
+        let mut iter = t.iter();
+        assert_eq!(iter.len(), 20);
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&19));
+        assert_eq!(iter.len(), 18);
+        assert!(iter.copied().eq(1..19));
+
+        assert!(t.iter().rev().copied().eq((0..20).rev()));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..20 {
+            t.insert(0, 19 - i);
+        }
+
+        let mut iter = t.iter_mut();
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        *first += 100;
+        *last += 100;
+        assert_eq!(iter.len(), 18);
+
+        for elem in t.iter_mut().rev() {
+            *elem *= 2;
+        }
+
+        assert_eq!(t[0], 200);
+        assert_eq!(t[19], 238);
+        assert!(t
+            .iter()
+            .skip(1)
+            .take(18)
+            .copied()
+            .eq((1..19).map(|i| i * 2)));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..20 {
+            t.insert(0, (19 - i).to_string());
+        }
+
+        let mut iter = t.clone().into_iter();
+        assert_eq!(iter.len(), 20);
+        assert_eq!(iter.next(), Some("0".to_string()));
+        assert_eq!(iter.next_back(), Some("19".to_string()));
+        assert_eq!(iter.next_back(), Some("18".to_string()));
+        assert_eq!(iter.len(), 17);
+        assert!(iter.eq((1..18).map(|i| i.to_string())));
+
+        // dropping part way through releases whatever was not yielded
+        let mut iter = t.clone().into_iter();
+        for _ in 0..5 {
+            iter.next();
+        }
+        iter.next_back();
+        drop(iter);
+
+        let mut visited = 0;
+        for elem in &t {
+            assert_eq!(*elem, visited.to_string());
+            visited += 1;
+        }
+        assert_eq!(visited, 20);
+
+        for elem in &mut t {
+            elem.push('!');
+        }
+        assert!(t
+            .into_iter()
+            .rev()
+            .eq((0..20).rev().map(|i| format!("{}!", i))));
+    }
+
+    #[test]
+    fn extend() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        t.push(0);
+
+        t.extend(1..1000);
+        assert_eq!(t.capacity(), 1024);
+        assert_eq!(t.expansions, 1);
+        assert!(t.validate().is_ok());
+
+        // filtered iterators only report a lower bound of zero and grow as they go
+        t.extend((1000..1100).filter(|i| i % 2 == 0));
+        t.extend(&[7, 8, 9]);
+        assert_eq!(t.len(), 1053);
+        assert!(t.iter().take(1000).copied().eq(0..1000));
+        assert!(t
+            .iter()
+            .skip(1000)
+            .copied()
+            .eq((1000..1100).step_by(2).chain(7..10)));
+
+        let collected: LinkedTieredVec<usize> = (0..100).collect();
+        assert!(collected.iter().copied().eq(0..100));
+    }
+
+    #[test]
+    fn truncate_and_clear() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..14 {
+            t.insert(0, (13 - i).to_string());
+        }
+
+        t.truncate(20);
+        assert_eq!(t.len(), 14);
+
+        t.truncate(6);
+        assert_eq!(t.len(), 6);
+        assert!(t.validate().is_ok());
+        assert!(t
+            .iter()
+            .eq((0..6).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert_eq!(t.slot_state(2, 0), SlotState::Free);
+
+        // a fully emptied tier is rewound so it starts from the first slot again
+        t.truncate(4);
+        assert_eq!(t.slot_state(1, 0), SlotState::Free);
+        t.push("4".to_string());
+        assert_eq!(t.slot_state(1, 0), SlotState::Occupied);
+
+        t.clear();
+        assert!(t.is_empty());
+        assert_eq!(t.populated_tiers(), 0);
+        t.push("again".to_string());
+        assert_eq!(t[0], "again");
+    }
+
+    #[test]
+    fn split_off() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..14 {
+            t.insert(0, (13 - i).to_string());
+        }
+
+        let mut aligned = t.clone();
+        let back = aligned.split_off(8);
+        assert_eq!(aligned.len(), 8);
+        assert_eq!(back.len(), 6);
+        assert!(aligned.validate().is_ok());
+        assert!(back.validate().is_ok());
+        assert!(aligned
+            .iter()
+            .eq((0..8).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert!(back
+            .iter()
+            .eq((8..14).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+
+        let back = t.split_off(5);
+        assert!(t
+            .iter()
+            .eq((0..5).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert!(back
+            .iter()
+            .eq((5..14).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+
+        assert!(t.split_off(5).is_empty());
+        let all = t.split_off(0);
+        assert!(t.is_empty());
+        assert_eq!(all.len(), 5);
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+
+        assert_eq!(t.swap_remove(2), 2);
+        assert_eq!(t.len(), 9);
+        assert_eq!(t[2], 9);
+
+        assert_eq!(t.swap_remove(8), 8);
+        assert_eq!(t.swap_remove(0), 0);
+        assert!(t.iter().copied().eq([7, 1, 9, 3, 4, 5, 6]));
+        assert!(t.validate().is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_remove_out_of_range() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+        t.swap_remove(10);
+    }
+
+    #[test]
+    fn swap() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..10 {
+            t.insert(0, (9 - i).to_string());
+        }
+
+        t.swap(1, 2);
+        t.swap(9, 0);
+        t.swap(5, 5);
+        t.swap(3, 6);
+        assert!(t
+            .iter()
+            .map(String::as_str)
+            .eq(["9", "2", "1", "6", "4", "5", "3", "7", "8", "0"]));
+        assert!(t.validate().is_ok());
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut t: LinkedTieredVec<u64> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..5 {
+            t.insert(0, 4 - i);
+        }
+        t.remove(0);
+        t.insert(0, 0);
+
+        let src: Vec<u64> = (5..1000).collect();
+        t.extend_from_slice(&src[..3]);
+        t.extend_from_slice(&src[3..]);
+        t.extend_from_slice(&[]);
+
+        assert_eq!(t.len(), 1000);
+        assert_eq!(t.expansions, 1);
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq(0..1000));
     }
 
     #[test]
-    fn with_minimum_capacity() {
-        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_capacity(4);
-        assert_eq!(4, t.capacity());
-        assert_eq!(2, t.tier_capacity());
+    #[should_panic]
+    fn extend_from_slice_past_bound() {
+        let mut t: LinkedTieredVec<u64> = LinkedTieredVec::fixed_capacity(6);
+        t.extend_from_slice(&[0; 7]);
+    }
 
-        t = LinkedTieredVec::with_capacity(8);
-        assert_eq!(16, t.capacity());
-        assert_eq!(4, t.tier_capacity());
+    #[test]
+    fn from_vec_and_slice() {
+        let t = LinkedTieredVec::from((0..1000).collect::<Vec<usize>>());
+        assert_eq!(t.tier_capacity(), 32);
+        assert_eq!(t.expansions, 0);
+        assert_eq!(t.cascades, 0);
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq(0..1000));
 
-        t = LinkedTieredVec::with_capacity(128);
-        assert_eq!(256, t.capacity());
-        assert_eq!(16, t.tier_capacity());
+        let names = ["a".to_string(), "b".to_string(), "c".to_string()];
+        let t = LinkedTieredVec::from_slice(&names);
+        assert_eq!(t.tier_capacity(), 2);
+        assert!(t.iter().eq(names.iter()));
+
+        let t: LinkedTieredVec<usize> = Vec::new().into();
+        assert!(t.is_empty());
     }
 
     #[test]
-    fn insert() {
-        let size = 4;
-        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::new(size);
-        assert_eq!(t.tier_capacity(), size);
+    fn into_vec_and_to_vec() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..14 {
+            t.insert(0, (13 - i).to_string());
+        }
+        t.remove(0);
+        t.insert(0, "0".to_string());
 
-        for i in 0..size {
-            t.insert(i, i * 2);
-            assert_eq!(t.len(), i + 1);
+        let expected: Vec<String> = (0..14).map(|i| i.to_string()).collect();
+        assert_eq!(t.to_vec(), expected);
+        assert_eq!(t.into_vec(), expected);
+
+        let empty: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        assert!(empty.into_vec().is_empty());
+    }
+
+    #[test]
+    fn push_front_and_pop_front() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..1000 {
+            t.push_front(i);
         }
 
-        for i in 0..size {
-            let result = t.get(i);
-            assert!(result.is_some());
-            assert_eq!(*result.unwrap(), i * 2);
+        // a cascade is only needed while every tier is in use
+        assert!(t.cascades < 50);
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq((0..1000).rev()));
+
+        for i in (500..1000).rev() {
+            assert_eq!(t.pop_front(), i);
         }
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq((0..500).rev()));
 
-        assert_eq!(t.len(), size);
-        assert!(!t.is_empty());
-        assert!(!t.is_full());
+        while !t.is_empty() {
+            t.pop_front();
+        }
+        assert_eq!(t.validate(), Ok(()));
     }
 
     #[test]
-    fn expand() {
-        let size = 4;
-        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::new(size);
+    fn queue_near_capacity() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(32);
+        t.extend(0..1023);
+        for i in 1023..11_023 {
+            assert_eq!(t.pop_front(), i - 1023);
+            t.push(i);
+        }
 
-        for i in 0..size * size {
-            t.insert(i, i);
-            assert_eq!(t[i], i);
+        // the first push to meet the front gap grows, so the only moves close that gap of two
+        // ranks on the way
+        assert_eq!(t.expansions, 1);
+        assert_eq!(t.moves, 2 * 31);
+        assert!(t.validate().is_ok());
+        assert!(t.iter().copied().eq(10_000..11_023));
+
+        // a bounded vector closes the gap instead, carrying at most one element per tier
+        let mut bounded: LinkedTieredVec<usize> = LinkedTieredVec::fixed_capacity(1024);
+        bounded.extend(0..1023);
+        for i in 1023..11_023 {
+            bounded.pop_front();
+            bounded.push(i);
         }
-        assert_eq!(t.tier_capacity(), size);
-        assert_eq!(t.len(), size * size);
+
+        assert_eq!(bounded.capacity(), 1024);
+        assert!(bounded.moves <= 10_000 * 32);
+        assert!(bounded.validate().is_ok());
+        assert!(bounded.iter().copied().eq(10_000..11_023));
+    }
+
+    #[test]
+    fn matches_vec_deque_under_deque_operations() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(512);
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        let mut v: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+
+        for i in 0..4_000 {
+            match rng.gen_range(0..6) {
+                0 | 1 => {
+                    t.push_front(i);
+                    v.push_front(i);
+                }
+                2 => {
+                    t.push(i);
+                    v.push_back(i);
+                }
+                3 => {
+                    let index = rng.gen_range(0..=v.len());
+                    t.insert(index, i);
+                    v.insert(index, i);
+                }
+                4 if !v.is_empty() => {
+                    assert_eq!(t.pop_front(), v.pop_front().unwrap());
+                }
+                _ if !v.is_empty() => {
+                    let index = rng.gen_range(0..v.len());
+                    assert_eq!(t.remove(index), v.remove(index).unwrap());
+                }
+                _ => {}
+            }
+
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.len(), v.len());
+        }
+
+        assert!(t.iter().eq(v.iter()));
+        assert!(t.clone().into_iter().rev().eq(v.iter().rev().copied()));
+        assert_eq!(t.to_vec(), Vec::from(v.clone()));
+
+        let back = t.split_off(v.len() / 3);
+        let v_back = v.split_off(v.len() / 3);
+        assert!(t.iter().eq(v.iter()));
+        assert!(back.iter().eq(v_back.iter()));
+    }
+
+    #[test]
+    fn boundary_accessors() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert!(t.first().is_none() && t.last().is_none());
+        assert!(t.front_mut().is_none() && t.back_mut().is_none());
+
+        for i in 0..10 {
+            t.push(i);
+        }
+        t.push_front(100);
+
+        assert_eq!(t.front(), Some(&100));
+        assert_eq!(t.back(), Some(&9));
+
+        *t.first_mut().unwrap() += 1;
+        *t.last_mut().unwrap() += 1;
+        assert_eq!(t.first(), Some(&101));
+        assert_eq!(t.last(), Some(&10));
+        assert_eq!(t[t.len() - 1], 10);
+    }
+
+    #[test]
+    fn rotate_left_and_right() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        let mut v: Vec<usize> = Vec::new();
+        for i in 0..12 {
+            t.push(i);
+            v.push(i);
+        }
+
+        // twelve elements fill three tiers, so whole tiers move by re-linking
+        t.rotate_left(8);
+        v.rotate_left(8);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        t.rotate_right(5);
+        v.rotate_right(5);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        v.extend(12..16);
+        // a full vector rotates with no free tier to spare
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        t.extend(v.iter().copied());
         assert!(t.is_full());
 
-        t.insert(size * size, size * size);
-        assert_eq!(t.tier_capacity(), size * 2);
-        assert_eq!(t.len(), (size * size) + 1);
-        assert!(!t.is_full());
+        for k in [0, 1, 4, 7, 9, 16] {
+            t.rotate_left(k);
+            v.rotate_left(k);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
 
-        for i in 0..((size * size) + 1) {
-            let result = t.get(i);
-            assert!(result.is_some());
-            assert_eq!(*result.unwrap(), i);
+            t.rotate_right(k);
+            v.rotate_right(k);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
         }
+
+        t.pop_front();
+        v.remove(0);
+        for k in 0..=v.len() {
+            t.rotate_left(k);
+            v.rotate_left(k);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+        assert_eq!(t.capacity(), 16);
     }
 
     #[test]
-    fn remove() {
-        let size = 16;
-        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::new(size);
-        assert_eq!(t.capacity(), size * size);
+    fn rotate_unaligned() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        let mut v: Vec<usize> = (0..13).collect();
+        t.extend(0..13);
 
-        for i in 0..size * size / 8 {
-            t.insert(i, i);
-            assert_eq!(t[i], i);
+        // thirteen elements leave the last tier with one, so every moved tier is rebalanced
+        t.rotate_left(9);
+        v.rotate_left(9);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        for len in 9..=16 {
+            for front in 0..4 {
+                for k in 0..=len {
+                    let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+                    t.extend(front..len);
+                    for i in (0..front).rev() {
+                        t.push_front(i);
+                    }
+                    let mut v: Vec<usize> = (0..len).collect();
+
+                    t.rotate_left(k);
+                    v.rotate_left(k);
+                    assert_eq!(t.validate(), Ok(()));
+                    assert_eq!(t.to_vec(), v);
+
+                    t.rotate_right(k);
+                    v.rotate_right(k);
+                    assert_eq!(t.validate(), Ok(()));
+                    assert_eq!(t.to_vec(), v);
+                    assert_eq!(t.capacity(), 16);
+                }
+            }
         }
-        assert_eq!(t.tier_capacity(), size);
-        assert_eq!(t.len(), size * size / 8);
-        assert_eq!(t.capacity(), size * size);
+    }
 
-        t.remove(0);
+    #[test]
+    #[should_panic(expected = "rotation by 3 out of range for length 2")]
+    fn rotate_left_past_len() {
+        let mut t: LinkedTieredVec<usize> = (0..2).collect();
+        t.rotate_left(3);
+    }
 
-        assert_eq!(*t.get(0).unwrap(), 1);
-        assert_eq!(t.len(), (size * size / 8) - 1);
+    #[test]
+    fn sort() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in 0..40 {
+            t.push_front(((i * 37) % 40).to_string());
+        }
+        let capacity = t.capacity();
 
-        t.remove(0);
-        assert_eq!(*t.get(0).unwrap(), 2);
-        assert_eq!(t.len(), (size * size / 8) - 2);
+        let mut expected: Vec<String> = (0..40).map(|i| i.to_string()).collect();
+        expected.sort();
+
+        t.sort();
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), expected);
+        assert_eq!(t.capacity(), capacity);
+
+        t.sort_unstable_by(|a, b| b.cmp(a));
+        expected.reverse();
+        assert_eq!(t.to_vec(), expected);
+
+        // stable sorting keeps equal keys in their current order
+        t.sort_by_key(|s| s.len());
+        assert_eq!(t[0], "9");
+        assert_eq!(t[9], "0");
+        assert_eq!(t[10], "39");
+        assert!(t.iter().map(String::len).is_sorted());
     }
 
     #[test]
-    fn contract() {}
+    fn binary_search() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i * 2);
+        }
+        t.pop_front();
+        assert!(t.is_sorted());
+
+        let v = t.to_vec();
+        for x in 0..62 {
+            assert_eq!(t.binary_search(&x), v.binary_search(&x));
+        }
+        assert_eq!(t.binary_search_by_key(&29, |elem| elem / 2), Ok(28));
+
+        t.swap(0, 1);
+        assert!(!t.is_sorted());
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.binary_search(&3), Err(0));
+        assert!(empty.is_sorted());
+    }
+
+    #[test]
+    fn partition_point_and_bounds() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i / 3);
+        }
+        t.pop_front();
+
+        let v = t.to_vec();
+        for x in 0..15 {
+            assert_eq!(
+                t.partition_point(|&elem| elem < x),
+                v.partition_point(|&elem| elem < x)
+            );
+            assert_eq!(t.lower_bound(&x), v.partition_point(|&elem| elem < x));
+            assert_eq!(t.upper_bound(&x), v.partition_point(|&elem| elem <= x));
+        }
+
+        let rank = t.upper_bound(&5);
+        t.insert(rank, 5);
+        assert!(t.is_sorted());
+        assert_eq!(t[rank - 1], 5);
+        assert_eq!(t[rank + 1], 6);
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.partition_point(|_| true), 0);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        let mut v: Vec<String> = Vec::new();
+        for i in 0..60 {
+            let elem = (i / 4 + i % 2).to_string();
+            t.push_front(elem.clone());
+            v.insert(0, elem);
+        }
+
+        t.dedup();
+        v.dedup();
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        t.dedup_by_key(|s| s.len());
+        v.dedup_by_key(|s| s.len());
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        // the closure sees the later element first, like `Vec::dedup_by`
+        let mut t: LinkedTieredVec<usize> = [1, 2, 4, 5, 9, 10, 11].into_iter().collect();
+        t.dedup_by(|a, b| *a == *b + 1);
+        assert_eq!(t.to_vec(), [1, 4, 9, 11]);
+    }
+
+    #[test]
+    fn slices() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
+        }
+
+        let window = t.slice(5..20);
+        assert_eq!(window.len(), 15);
+        assert_eq!(window.get(0), Some(&5));
+        assert_eq!(window.get(15), None);
+        assert_eq!(window[14], 19);
+        assert!(window.iter().copied().eq(5..20));
+
+        let inner = window.slice(2..=4);
+        assert!(inner.into_iter().copied().eq(7..10));
+        assert!(t.slice(..).iter().copied().eq(0..30));
+        assert!(t.slice(30..).is_empty());
+
+        let mut window = t.slice_mut(10..);
+        window[0] = 100;
+        *window.get_mut(19).unwrap() = 200;
+        assert_eq!(window.get_mut(20), None);
+        for elem in window.slice_mut(1..3) {
+            *elem += 1000;
+        }
+        assert_eq!(
+            window.slice(..4).iter().copied().collect::<Vec<_>>(),
+            [100, 1011, 1012, 13]
+        );
+
+        assert_eq!(t[10], 100);
+        assert_eq!(t[29], 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 5 out of range for a vector of len 3")]
+    fn slice_out_of_range() {
+        let t: LinkedTieredVec<usize> = (0..10).collect();
+        let window = t.slice(2..5);
+        window.slice(1..5);
+    }
+
+    #[test]
+    fn iter_range() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i);
+        }
+
+        assert!(t.iter_range(3..29).copied().eq(3..29));
+        assert!(t.iter_range(3..29).rev().copied().eq((3..29).rev()));
+        assert!(t.iter_range(..=0).copied().eq(0..1));
+        assert!(t.iter_range(40..).next().is_none());
+
+        // both ends can meet in the middle of a tier
+        let mut iter = t.iter_range(5..9);
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&8));
+        assert_eq!(iter.len(), 2);
+        assert!(iter.copied().eq(6..8));
+
+        for elem in t.iter_range_mut(10..20) {
+            *elem *= 10;
+        }
+        for elem in t.iter_range_mut(30..).rev() {
+            *elem = 0;
+        }
+        assert_eq!(t[9], 9);
+        assert_eq!(t[10], 100);
+        assert_eq!(t[19], 190);
+        assert_eq!(t[20], 20);
+        assert!(t.iter_range(30..).all(|&elem| elem == 0));
+    }
+
+    #[test]
+    fn chunks_and_windows() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
+        }
+        let v = t.to_vec();
+
+        let chunks = t.chunks(7);
+        assert_eq!(chunks.len(), 5);
+        for (chunk, expected) in chunks.zip(v.chunks(7)) {
+            assert!(chunk.iter().eq(expected.iter()));
+        }
+
+        let windows = t.windows(5);
+        assert_eq!(windows.len(), 26);
+        for (window, expected) in windows.zip(v.windows(5)) {
+            assert!(window.iter().eq(expected.iter()));
+        }
+
+        let sums: Vec<usize> = t.windows(3).map(|w| w.iter().sum()).collect();
+        assert_eq!(sums[0], 3);
+        assert_eq!(sums[27], 84);
+
+        assert_eq!(t.windows(31).count(), 0);
+        assert_eq!(t.chunks(100).count(), 1);
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.chunks(3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_of_zero() {
+        let t: LinkedTieredVec<usize> = (0..10).collect();
+        t.chunks(0);
+    }
+
+    #[test]
+    fn segments() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i);
+        }
+        t.remove(5);
+        t.insert(5, 5);
+
+        let segments: Vec<&[usize]> = t.segments().collect();
+        assert!(segments.iter().all(|segment| !segment.is_empty()));
+        assert!(segments.len() >= t.populated_tiers());
+        assert!(segments.concat().into_iter().eq(0..30));
+
+        for segment in t.segments_mut() {
+            for elem in segment.iter_mut() {
+                *elem *= 2;
+            }
+        }
+        assert!(t.iter().copied().eq((0..30).map(|i| i * 2)));
+
+        let empty: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        assert_eq!(empty.segments().count(), 0);
+    }
+
+    #[test]
+    fn copy_within_and_move_range() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i);
+        }
+        t.remove(6);
+        t.insert(6, 6);
+        let mut v = t.to_vec();
+
+        for (src, dest) in [(3..20, 9), (9..26, 3), (0..40, 0), (30..40, 1), (5..5, 39)] {
+            t.copy_within(src.clone(), dest);
+            v.copy_within(src, dest);
+            assert_eq!(t.to_vec(), v);
+        }
+
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i.to_string());
+        }
+        let mut v = t.to_vec();
+
+        for (src, dest) in [
+            (3..20, 9),
+            (25..40, 0),
+            (0..1, 39),
+            (10..30, 10),
+            (12..13, 2),
+        ] {
+            t.move_range(src.clone(), dest);
+
+            let block: Vec<String> = v.drain(src).collect();
+            v.splice(dest..dest, block);
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "destination 35 out of range for 10 ranks in a vector of len 40")]
+    fn move_range_past_end() {
+        let mut t: LinkedTieredVec<usize> = (0..40).collect();
+        t.move_range(0..10, 35);
+    }
+
+    #[test]
+    fn swap_ranges() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i.to_string());
+        }
+        let mut v = t.to_vec();
+
+        for (a, b) in [
+            (0..10, 10..20),
+            (33..40, 3..10),
+            (5..6, 39..40),
+            (7..7, 8..8),
+        ] {
+            t.swap_ranges(a.clone(), b.clone());
+
+            let (low, high) = if a.start < b.start { (a, b) } else { (b, a) };
+            let (front, back) = v.split_at_mut(high.start);
+            front[low].swap_with_slice(&mut back[..high.len()]);
+
+            assert_eq!(t.validate(), Ok(()));
+            assert_eq!(t.to_vec(), v);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "swapped ranges must not overlap")]
+    fn swap_overlapping_ranges() {
+        let mut t: LinkedTieredVec<usize> = (0..40).collect();
+        t.swap_ranges(0..10, 5..15);
+    }
+
+    #[test]
+    fn insert_slice_and_insert_iter() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..40).rev() {
+            t.push_front(i.to_string());
+        }
+        let mut v = t.to_vec();
+
+        let words: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        t.insert_slice(7, &words);
+        v.splice(7..7, words.iter().cloned());
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        t.insert_iter(0, (100..130).map(|i| i.to_string()));
+        v.splice(0..0, (100..130).map(|i| i.to_string()));
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), v);
+
+        let len = t.len();
+        t.insert_slice(len, &words);
+        t.insert_iter(3, std::iter::empty());
+        v.extend(words);
+        assert_eq!(t.to_vec(), v);
+    }
+
+    #[test]
+    fn apply_edits() {
+        let mut t: LinkedTieredVec<String> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..30).rev() {
+            t.push_front(i.to_string());
+        }
+
+        t.apply_edits(vec![
+            Edit::Insert(0, "a".to_string()),
+            Edit::Remove(0),
+            Edit::Replace(5, "b".to_string()),
+            Edit::Insert(6, "c".to_string()),
+            Edit::Insert(6, "d".to_string()),
+            Edit::Remove(6),
+            Edit::Remove(7),
+            Edit::Insert(30, "e".to_string()),
+        ]);
+
+        let mut expected = vec!["a".to_string()];
+        expected.extend((1..5).map(|i| i.to_string()));
+        expected.extend(["b", "c", "d"].iter().map(|s| s.to_string()));
+        expected.extend((8..30).map(|i| i.to_string()));
+        expected.push("e".to_string());
+
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.to_vec(), expected);
+
+        let len = t.len();
+        t.apply_edits((0..len).map(Edit::Remove));
+        assert!(t.is_empty());
+
+        t.apply_edits((0..100).map(|i| Edit::Insert(0, i.to_string())));
+        assert_eq!(
+            t.to_vec(),
+            (0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "edit at rank 3 is out of order")]
+    fn apply_edits_out_of_order() {
+        let mut t: LinkedTieredVec<usize> = (0..10).collect();
+        t.apply_edits(vec![Edit::Remove(3), Edit::Insert(3, 10)]);
+    }
+
+    #[test]
+    fn fallible_operations() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        assert_eq!(t.try_pop(), Err(TieredVectorError::Empty));
+        assert_eq!(
+            t.try_remove(0),
+            Err(TieredVectorError::IndexOutOfBounds {
+                index: 0,
+                len: 0,
+                elem: None
+            })
+        );
+
+        for i in 0..10 {
+            assert_eq!(t.try_push(i), Ok(()));
+        }
+        assert_eq!(
+            t.try_insert(11, 11),
+            Err(TieredVectorError::IndexOutOfBounds {
+                index: 11,
+                len: 10,
+                elem: Some(11)
+            })
+        );
+        assert_eq!(t.try_insert(10, 10), Ok(()));
+        assert_eq!(t.try_remove(3), Ok(3));
+        assert_eq!(t.try_pop(), Ok(10));
+        assert_eq!(t.to_vec(), vec![0, 1, 2, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 is past the end of a vector of len 3")]
+    fn insert_past_end() {
+        let mut t: LinkedTieredVec<usize> = (0..3).collect();
+        t.insert(4, 4);
+    }
+
+    #[test]
+    fn debug() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        assert_eq!(format!("{:?}", t), "[]");
+
+        t.extend(0..3);
+        assert_eq!(format!("{:?}", t), "[0, 1, 2]");
+
+        let pretty = format!("{:#?}", t);
+        assert!(pretty.starts_with("LinkedTieredVec {\n    len: 3,\n    tier_capacity: 2,\n"));
+        assert!(pretty.contains("tier_lens: [\n        2,\n        1,\n    ],"));
+    }
+
+    #[test]
+    fn equality() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        t.extend(0..3);
+
+        let mut u: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..3).rev() {
+            u.push_front(i);
+        }
+        assert_eq!(t, u);
+        assert_eq!(t, vec![0, 1, 2]);
+        assert_eq!(t, [0, 1, 2]);
+        assert_eq!(t, &[0, 1, 2][..]);
+
+        u.pop();
+        assert_ne!(t, u);
+        assert_ne!(t, vec![0, 1]);
+        assert_ne!(t, [0, 1, 3]);
+    }
+
+    #[test]
+    fn lazy_allocation() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::new();
+        assert_eq!(t.capacity(), 0);
+        assert_eq!(t.tiers.capacity(), 0);
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.iter().count(), 0);
+        assert_eq!(t.clone(), t);
+        assert!(t.split_off(0).is_empty());
+        t.rotate_by_tier(1);
+        assert_eq!(t.capacity(), 0);
+
+        let predicted = t.capacity_after_expand();
+        t.push(0);
+        assert_eq!(t.capacity(), predicted);
+        assert_eq!(t.validate(), Ok(()));
+
+        let mut u: LinkedTieredVec<usize> = Default::default();
+        u.push_front(0);
+        assert_eq!(t, u);
+
+        let mut u: LinkedTieredVec<usize> = LinkedTieredVec::new();
+        u.extend_from_slice(&[0, 1, 2, 3, 4]);
+        t.extend(1..5);
+        assert_eq!(t, u);
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut t: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(4);
+        for i in (0..10).rev() {
+            t.push_front(i);
+        }
+
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(json, "[0,1,2,3,4,5,6,7,8,9]");
+
+        let u: LinkedTieredVec<usize> = serde_json::from_str(&json).unwrap();
+        assert_eq!(u.validate(), Ok(()));
+        assert_eq!(u, t);
+        assert_eq!(u.capacity(), 16);
+
+        let empty: LinkedTieredVec<usize> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+        assert!(serde_json::from_str::<LinkedTieredVec<usize>>("[1,\"a\"]").is_err());
+    }
 }
@@ -0,0 +1,236 @@
+use crate::{FlatTieredVec, LinkedTieredVec};
+
+pub trait Sequence<T> {
+    fn len(&self) -> usize;
+
+    fn get(&self, index: usize) -> Option<&T>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// both representations panic on out-of-range ranks, so the trait keeps the panicking signatures
+pub trait TieredSequence<T>: Sequence<T> {
+    fn tier_capacity(&self) -> usize;
+
+    fn insert(&mut self, index: usize, elem: T);
+
+    fn remove(&mut self, index: usize) -> T;
+
+    fn push(&mut self, elem: T);
+
+    fn pop(&mut self) -> T;
+}
+
+impl<T> Sequence<T> for LinkedTieredVec<T> {
+    fn len(&self) -> usize {
+        LinkedTieredVec::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        LinkedTieredVec::get(self, index)
+    }
+}
+
+impl<T> Sequence<T> for FlatTieredVec<T> {
+    fn len(&self) -> usize {
+        FlatTieredVec::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        FlatTieredVec::get(self, index)
+    }
+}
+
+impl<T> TieredSequence<T> for LinkedTieredVec<T> {
+    fn tier_capacity(&self) -> usize {
+        LinkedTieredVec::tier_capacity(self)
+    }
+
+    fn insert(&mut self, index: usize, elem: T) {
+        LinkedTieredVec::insert(self, index, elem)
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        LinkedTieredVec::remove(self, index)
+    }
+
+    fn push(&mut self, elem: T) {
+        LinkedTieredVec::push(self, elem)
+    }
+
+    fn pop(&mut self) -> T {
+        LinkedTieredVec::pop(self)
+    }
+}
+
+impl<T> TieredSequence<T> for FlatTieredVec<T> {
+    fn tier_capacity(&self) -> usize {
+        FlatTieredVec::tier_capacity(self)
+    }
+
+    fn insert(&mut self, index: usize, elem: T) {
+        FlatTieredVec::insert(self, index, elem)
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        FlatTieredVec::remove(self, index)
+    }
+
+    fn push(&mut self, elem: T) {
+        FlatTieredVec::push(self, elem)
+    }
+
+    fn pop(&mut self) -> T {
+        FlatTieredVec::pop(self)
+    }
+}
+
+// borrows either representation behind one concrete type, without going through a trait object
+pub struct SequenceView<'a, T> {
+    inner: ViewInner<'a, T>,
+}
+
+enum ViewInner<'a, T> {
+    Linked(&'a LinkedTieredVec<T>),
+    Flat(&'a FlatTieredVec<T>),
+}
+
+impl<'a, T> SequenceView<'a, T> {
+    pub fn len(&self) -> usize {
+        match self.inner {
+            ViewInner::Linked(vec) => vec.len(),
+            ViewInner::Flat(vec) => vec.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match self.inner {
+            ViewInner::Linked(vec) => vec.get(index),
+            ViewInner::Flat(vec) => vec.get(index),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..view.len()).map(move |i| view.get(i).expect("rank is below len"))
+    }
+}
+
+impl<T> Clone for SequenceView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SequenceView<'_, T> {}
+
+impl<T> Clone for ViewInner<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ViewInner<'_, T> {}
+
+impl<T> Sequence<T> for SequenceView<'_, T> {
+    fn len(&self) -> usize {
+        SequenceView::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        SequenceView::get(self, index)
+    }
+}
+
+impl<T> LinkedTieredVec<T> {
+    pub fn view(&self) -> SequenceView<'_, T> {
+        SequenceView {
+            inner: ViewInner::Linked(self),
+        }
+    }
+}
+
+impl<T> FlatTieredVec<T> {
+    pub fn view(&self) -> SequenceView<'_, T> {
+        SequenceView {
+            inner: ViewInner::Flat(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sum<S: Sequence<usize>>(sequence: &S) -> usize {
+        (0..sequence.len()).map(|i| sequence.get(i).unwrap()).sum()
+    }
+
+    #[test]
+    fn generic_over_sequence() {
+        let linked: LinkedTieredVec<usize> = (0..50).collect();
+        let flat: FlatTieredVec<usize> = (0..50).collect();
+        let empty: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+
+        assert_eq!(sum(&linked), 1225);
+        assert_eq!(sum(&flat), 1225);
+        assert_eq!(sum(&linked.view()), 1225);
+        assert_eq!(sum(&flat.view()), 1225);
+        assert!(Sequence::is_empty(&empty));
+    }
+
+    #[test]
+    fn view() {
+        let linked: LinkedTieredVec<usize> = (0..20).collect();
+        let flat: FlatTieredVec<usize> = (0..20).collect();
+
+        for view in [linked.view(), flat.view()] {
+            assert_eq!(view.len(), 20);
+            assert_eq!(view.get(7), Some(&7));
+            assert_eq!(view.get(20), None);
+            assert!(view.iter().copied().eq(0..20));
+        }
+    }
+
+    fn shuffle_through<S: TieredSequence<usize>>(sequence: &mut S) {
+        for i in 0..20 {
+            sequence.insert(i / 2, i);
+        }
+        sequence.push(100);
+        assert_eq!(sequence.pop(), 100);
+        assert_eq!(sequence.remove(0), 1);
+    }
+
+    #[test]
+    fn generic_over_tiered_sequence() {
+        let mut linked: LinkedTieredVec<usize> = LinkedTieredVec::with_tier_capacity(2);
+        let mut flat: FlatTieredVec<usize> = FlatTieredVec::with_tier_capacity(2);
+
+        shuffle_through(&mut linked);
+        shuffle_through(&mut flat);
+
+        assert_eq!(linked.len(), 19);
+        assert_eq!(TieredSequence::tier_capacity(&linked), 8);
+        for i in 0..linked.len() {
+            assert_eq!(linked[i], flat[i]);
+        }
+
+        let mut sequences: Vec<Box<dyn TieredSequence<usize>>> = vec![
+            Box::new(LinkedTieredVec::with_tier_capacity(4)),
+            Box::new(FlatTieredVec::with_tier_capacity(4)),
+        ];
+        for sequence in sequences.iter_mut() {
+            sequence.push(1);
+            sequence.insert(0, 0);
+            assert_eq!(sequence.len(), 2);
+            assert_eq!(sequence.get(1), Some(&1));
+            assert_eq!(sequence.tier_capacity(), 4);
+        }
+    }
+}
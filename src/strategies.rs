@@ -0,0 +1,216 @@
+use std::{fmt::Debug, sync::Arc};
+
+use proptest::{
+    collection::{vec, SizeRange},
+    prelude::*,
+    sample::Index,
+};
+
+use crate::{FlatTieredVec, LinkedTieredVec};
+
+// the internal state a generated vector is left in, simplest first so shrinking heads there
+#[derive(Debug, Clone)]
+enum Shape {
+    Packed,
+    // the elements past the index are pushed to the back and the rest onto the front
+    Wrapped(Index),
+    // drawn at a length that fills every tier, picked by the index, so the next push expands
+    Full(Index),
+    // built with oversized tiers and contracted once
+    Contracted,
+}
+
+fn shape() -> impl Strategy<Value = Shape> {
+    prop_oneof![
+        Just(Shape::Packed),
+        any::<Index>().prop_map(Shape::Wrapped),
+        any::<Index>().prop_map(Shape::Full),
+        Just(Shape::Contracted),
+    ]
+}
+
+// the lengths within `size` that fill every tier of a vector sized for them
+fn full_lens(size: &SizeRange) -> Vec<usize> {
+    (1..usize::BITS / 2)
+        .map(|shift| 1 << (2 * shift))
+        .filter(|len| (size.start()..=size.end_incl()).contains(len))
+        .collect()
+}
+
+// a full vector cannot be cut from the generated elements without leaving `size`, so its length
+// is drawn before them; a range holding no such length leaves the elements packed instead
+fn shaped_elements<S>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = (Vec<S::Value>, Shape)>
+where
+    S: Strategy,
+    S::Value: Debug,
+{
+    let size = size.into();
+    let full_lens = full_lens(&size);
+    let element = Arc::new(element);
+
+    shape().prop_flat_map(move |shape| {
+        let size = match &shape {
+            Shape::Full(index) if !full_lens.is_empty() => {
+                let len = full_lens[index.index(full_lens.len())];
+                SizeRange::from(len..=len)
+            }
+            _ => size.clone(),
+        };
+
+        (vec(Arc::clone(&element), size), Just(shape))
+    })
+}
+
+fn flat_with_shape<T>(mut elements: Vec<T>, shape: Shape) -> FlatTieredVec<T> {
+    match shape {
+        Shape::Wrapped(index) => {
+            let back = elements.split_off(index.index(elements.len() + 1));
+            let mut tiered_vec = FlatTieredVec::with_capacity(elements.len() + back.len() + 4);
+            tiered_vec.extend(back);
+            for elem in elements.into_iter().rev() {
+                tiered_vec.push_front(elem);
+            }
+
+            tiered_vec
+        }
+        // sized once for its length, a vector of a full length has every tier full
+        Shape::Full(_) | Shape::Packed => FlatTieredVec::from(elements),
+        Shape::Contracted => {
+            let packed = FlatTieredVec::<T>::with_capacity(elements.len().max(4));
+            let mut tiered_vec = FlatTieredVec::with_tier_capacity(packed.tier_capacity() << 2);
+            tiered_vec.extend(elements);
+            tiered_vec.try_contract(tiered_vec.len());
+
+            tiered_vec
+        }
+    }
+}
+
+pub fn flat_tiered_vec<S>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = FlatTieredVec<S::Value>>
+where
+    S: Strategy,
+    S::Value: Debug,
+{
+    shaped_elements(element, size).prop_map(|(elements, shape)| flat_with_shape(elements, shape))
+}
+
+fn linked_with_shape<T>(mut elements: Vec<T>, shape: Shape) -> LinkedTieredVec<T> {
+    match shape {
+        Shape::Wrapped(index) => {
+            let back = elements.split_off(index.index(elements.len() + 1));
+            let mut tiered_vec = LinkedTieredVec::with_capacity(elements.len() + back.len() + 4);
+            tiered_vec.extend(back);
+            for elem in elements.into_iter().rev() {
+                tiered_vec.push_front(elem);
+            }
+
+            tiered_vec
+        }
+        // sized once for its length, a vector of a full length has every tier full
+        Shape::Full(_) | Shape::Packed => LinkedTieredVec::from(elements),
+        Shape::Contracted => {
+            let packed = LinkedTieredVec::<T>::with_capacity(elements.len().max(4));
+            let mut tiered_vec = LinkedTieredVec::with_tier_capacity(packed.tier_capacity() << 2);
+            tiered_vec.extend(elements);
+            tiered_vec.try_contract(tiered_vec.len());
+
+            tiered_vec
+        }
+    }
+}
+
+pub fn linked_tiered_vec<S>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = LinkedTieredVec<S::Value>>
+where
+    S: Strategy,
+    S::Value: Debug,
+{
+    shaped_elements(element, size).prop_map(|(elements, shape)| linked_with_shape(elements, shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{strategy::ValueTree, test_runner::TestRunner};
+
+    use super::*;
+
+    #[test]
+    fn full_lens() {
+        assert_eq!(super::full_lens(&(0..4).into()), Vec::<usize>::new());
+        assert_eq!(super::full_lens(&(50..=50).into()), Vec::<usize>::new());
+        assert_eq!(super::full_lens(&(0..=16).into()), vec![4, 16]);
+        assert_eq!(super::full_lens(&(5..100).into()), vec![16, 64]);
+    }
+
+    #[test]
+    fn shapes() {
+        let mut runner = TestRunner::deterministic();
+        let index = any::<Index>().new_tree(&mut runner).unwrap().current();
+
+        let t = flat_with_shape((0..16).collect(), Shape::Full(index));
+        assert!(t.is_full());
+
+        let t = linked_with_shape((0..16).collect(), Shape::Full(index));
+        assert!(t.is_full());
+
+        let t = flat_with_shape((0..40).collect(), Shape::Contracted);
+        assert_eq!(t.tier_capacity(), 16);
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..40));
+
+        let t = linked_with_shape((0..40).collect(), Shape::Contracted);
+        assert_eq!(t.tier_capacity(), 16);
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..40));
+
+        let wrapped = Shape::Wrapped(index);
+        let t = flat_with_shape((0..40).collect(), wrapped.clone());
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..40));
+
+        let t = linked_with_shape((0..40).collect(), wrapped);
+        assert_eq!(t.validate(), Ok(()));
+        assert!(t.iter().copied().eq(0..40));
+    }
+
+    proptest! {
+        #[test]
+        fn flat_strategy_yields_valid_vectors(t in flat_tiered_vec(any::<u8>(), 0..200)) {
+            prop_assert_eq!(t.validate(), Ok(()));
+            prop_assert_eq!(t.iter().count(), t.len());
+        }
+
+        #[test]
+        fn linked_strategy_yields_valid_vectors(t in linked_tiered_vec(any::<u8>(), 0..200)) {
+            prop_assert_eq!(t.validate(), Ok(()));
+            prop_assert_eq!(t.iter().count(), t.len());
+        }
+
+        #[test]
+        fn strategies_stay_within_size(
+            flat in flat_tiered_vec(any::<u8>(), 50..=50),
+            linked in linked_tiered_vec(any::<u8>(), 10..20),
+        ) {
+            prop_assert_eq!(flat.len(), 50);
+            prop_assert!((10..20).contains(&linked.len()));
+        }
+
+        #[test]
+        fn full_shape_fills_every_tier(
+            t in shaped_elements(any::<u8>(), 10..=20)
+                .prop_filter("full shape", |(_, shape)| matches!(shape, Shape::Full(_)))
+                .prop_map(|(elements, shape)| flat_with_shape(elements, shape)),
+        ) {
+            prop_assert!(t.is_full());
+            prop_assert_eq!(t.len(), 16);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#[test]
+fn borrow_contract() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/get_ref_until_mutation.rs");
+    t.compile_fail("tests/ui/get_ref_across_insert.rs");
+    t.compile_fail("tests/ui/get_ref_across_remove.rs");
+}
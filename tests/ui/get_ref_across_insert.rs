@@ -0,0 +1,9 @@
+use tiered_vec::LinkedTieredVec;
+
+fn main() {
+    let mut linked: LinkedTieredVec<usize> = (0..32).collect();
+    let first = linked.get(0).unwrap();
+
+    linked.insert(0, 100);
+    assert_eq!(*first, 0);
+}
@@ -0,0 +1,9 @@
+use tiered_vec::FlatTieredVec;
+
+fn main() {
+    let mut flat: FlatTieredVec<usize> = (0..32).collect();
+    let last = &flat[31];
+
+    flat.remove(0);
+    assert_eq!(*last, 31);
+}
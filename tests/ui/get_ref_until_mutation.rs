@@ -0,0 +1,19 @@
+use tiered_vec::{FlatTieredVec, LinkedTieredVec};
+
+fn main() {
+    let mut linked: LinkedTieredVec<usize> = (0..32).collect();
+    let first = linked.get(0).unwrap();
+    let last = &linked[31];
+    assert_eq!(*first + *last, 31);
+
+    linked.insert(0, 100);
+    assert_eq!(linked[0], 100);
+
+    let mut flat: FlatTieredVec<usize> = (0..32).collect();
+    let first = flat.get(0).unwrap();
+    let last = &flat[31];
+    assert_eq!(*first + *last, 31);
+
+    flat.insert(0, 100);
+    assert_eq!(flat[0], 100);
+}